@@ -0,0 +1,110 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{input, Answer, Part};
+
+/// Known-good answers, one per line: `<year> <day> <part> <answer> [input id]`.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// `None` for the default input, otherwise the `<id>` in `inputs/<year>/<day>-<id>.txt`.
+    pub input: Option<String>,
+    pub answer: Answer,
+}
+
+impl Expected {
+    pub fn input_path(&self) -> PathBuf {
+        match &self.input {
+            Some(id) => input::default_path(self.year, self.day)
+                .with_file_name(format!("{:02}-{}.txt", self.day, id)),
+            None => input::default_path(self.year, self.day),
+        }
+    }
+}
+
+/// Blank lines and lines starting with `#` are skipped. Errors name the line in `path`.
+pub fn parse(path: &str, text: &str) -> Result<Vec<Expected>> {
+    let mut answers = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let expected = parse_line(line).with_context(|| format!("{} line {}", path, i + 1))?;
+        if answers.iter().any(|other: &Expected| {
+            (other.year, other.day, other.part, &other.input)
+                == (expected.year, expected.day, expected.part, &expected.input)
+        }) {
+            bail!("{} line {}: duplicate answer", path, i + 1);
+        }
+        answers.push(expected);
+    }
+    Ok(answers)
+}
+
+fn parse_line(line: &str) -> Result<Expected> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let (year, day, part, answer, input) = match fields.as_slice() {
+        [year, day, part, answer] => (year, day, part, answer, None),
+        [year, day, part, answer, input] => (year, day, part, answer, Some(input.to_string())),
+        _ => bail!(
+            "expected `<year> <day> <part> <answer> [input id]`, got {:?}",
+            line
+        ),
+    };
+    let part = part
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .with_context(|| format!("part must be 1 or 2, got {:?}", part))?;
+    Ok(Expected {
+        year: year
+            .parse()
+            .with_context(|| format!("bad year {:?}", year))?,
+        day: day.parse().with_context(|| format!("bad day {:?}", day))?,
+        part,
+        input,
+        answer: Answer::from(*answer),
+    })
+}
+
+pub fn load(path: &str) -> Result<Vec<Expected>> {
+    let text = fs::read_to_string(path).with_context(|| format!("could not read {}", path))?;
+    parse(path, &text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_parse_answers() {
+        let text = "# year day part answer\n2023 5 1 35\n\n2023 05 2 46 sample\n";
+        let answers = parse(ANSWERS_FILE, text).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers[0].part, Part::One);
+        assert_eq!(answers[0].answer.as_str(), "35");
+        assert_eq!(answers[0].input_path(), PathBuf::from("inputs/2023/05.txt"));
+        assert_eq!(answers[1].input.as_deref(), Some("sample"));
+        assert_eq!(
+            answers[1].input_path(),
+            PathBuf::from("inputs/2023/05-sample.txt")
+        );
+    }
+
+    #[test]
+    fn could_reject_bad_lines() {
+        assert!(parse(ANSWERS_FILE, "2023 5 3 35").is_err());
+        assert!(parse(ANSWERS_FILE, "2023 5 1").is_err());
+        assert!(parse(ANSWERS_FILE, "2023 5 1 35\n2023 5 1 36").is_err());
+        let err = parse(ANSWERS_FILE, "2023 5 1 35\nyear 5 1 35").unwrap_err();
+        assert_eq!(err.to_string(), "answers.txt line 2");
+        let err = parse("other.txt", "2023 5 1 35\n2023 5 1 36").unwrap_err();
+        assert_eq!(err.to_string(), "other.txt line 2: duplicate answer");
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{error::Result, input::normalize, Part, Solver};
+
+/// What gets timed separately: parsing, then each part on the already parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Untimed runs before measuring, to warm caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        // Nearest-rank percentile.
+        let rank = |p: f64| samples[((p * samples.len() as f64).ceil() as usize).max(1) - 1];
+        Some(Self {
+            iterations: samples.len(),
+            min: *samples.first()?,
+            median: rank(0.5),
+            p95: rank(0.95),
+        })
+    }
+
+    /// One flat JSON object, so runs can be diffed across commits.
+    pub fn to_json(&self, year: u16, day: u8, stage: Stage) -> String {
+        format!(
+            r#"{{"year":{},"day":{},"stage":"{}","iterations":{},"min_ns":{},"median_ns":{},"p95_ns":{}}}"#,
+            year,
+            day,
+            stage,
+            self.iterations,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.p95.as_nanos()
+        )
+    }
+}
+
+pub type Timings = Vec<(Stage, Stats)>;
+
+fn measure<T>(options: Options, mut run: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..options.warmup {
+        black_box(run()?);
+    }
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations.max(1) {
+        let start = Instant::now();
+        black_box(run()?);
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(samples).expect("at least one iteration"))
+}
+
+pub(crate) fn bench_parts<S: Solver>(
+    input: &str,
+    parts: &[Part],
+    options: Options,
+) -> Result<Timings> {
+    let input = &normalize(input);
+    let in_day = |e: crate::Error| e.in_day(S::YEAR, S::DAY);
+    let mut timings = vec![];
+    let stats = measure(options, || S::parse(black_box(input))).map_err(in_day)?;
+    timings.push((Stage::Parse, stats));
+    let parsed = S::parse(input).map_err(in_day)?;
+    for &part in parts {
+        let stats = match part {
+            Part::One => measure(options, || S::part_one(black_box(&parsed))),
+            Part::Two => measure(options, || S::part_two(black_box(&parsed))),
+        };
+        timings.push((
+            Stage::Part(part),
+            stats.map_err(|e| in_day(e).in_part(part))?,
+        ));
+    }
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn could_summarize_samples() {
+        let samples = (1..=20).rev().map(ms).collect();
+        let stats = Stats::from_samples(samples).unwrap();
+        assert_eq!(stats.iterations, 20);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(10));
+        assert_eq!(stats.p95, ms(19));
+        let stats = Stats::from_samples(vec![ms(3)]).unwrap();
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(3), ms(3), ms(3)));
+        assert_eq!(Stats::from_samples(vec![]), None);
+    }
+
+    #[test]
+    fn could_write_json() {
+        let stats = Stats::from_samples(vec![ms(1), ms(2)]).unwrap();
+        assert_eq!(
+            stats.to_json(2023, 5, Stage::Part(Part::Two)),
+            r#"{"year":2023,"day":5,"stage":"part 2","iterations":2,"min_ns":1000000,"median_ns":1000000,"p95_ns":2000000}"#
+        );
+    }
+}
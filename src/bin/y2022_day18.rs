@@ -0,0 +1,9 @@
+use aoc::y2022::day18::{self, Day18};
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc::input::from_args::<Day18>()?;
+    let droplets = day18::parse(&input)?;
+    println!("Part 1: {}", day18::solve(&droplets)?);
+    println!("Part 2: {}", day18::solve2(&droplets)?);
+    Ok(())
+}
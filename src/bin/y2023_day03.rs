@@ -0,0 +1,9 @@
+use aoc::y2023::day03::{self, Day3};
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc::input::from_args::<Day3>()?;
+    let schematic = day03::parse(&input)?;
+    println!("Part 1: {}", day03::part1(&schematic)?);
+    println!("Part 2: {}", day03::part2(&schematic)?);
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use aoc::y2023::day05::{self, Day5};
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc::input::from_args::<Day5>()?;
+    let (seeds, maps) = day05::parse(&input)?;
+    println!("Part 1: {}", day05::solve_part_1(&seeds, &maps)?);
+    println!("Part 2: {}", day05::solve_part_2(&seeds, &maps)?);
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use aoc::y2023::day06::{self, Day6};
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc::input::from_args::<Day6>()?;
+    let (races, race) = day06::parse(&input)?;
+    println!("Part 1: {}", day06::solve_part_1(&races)?);
+    println!("Part 2: {}", day06::solve_part_2(&race)?);
+    Ok(())
+}
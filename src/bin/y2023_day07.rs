@@ -0,0 +1,8 @@
+use aoc::y2023::day07::{solve_stream, Day7};
+
+fn main() -> anyhow::Result<()> {
+    let (part1, part2) = solve_stream(aoc::input::reader_from_args::<Day7>()?)?;
+    println!("Part 1: {}", part1);
+    println!("Part 2: {}", part2);
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use aoc::y2023::day08::{self, Day8};
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc::input::from_args::<Day8>()?;
+    let (instruction, nodes) = day08::parse(&input)?;
+    println!("Part 1: {}", day08::part1(&instruction, &nodes)?);
+    println!("Part 2: {}", day08::part2(&instruction, &nodes)?);
+    Ok(())
+}
@@ -0,0 +1,319 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use ureq::Agent;
+
+use crate::{
+    input,
+    submission::{self, Verdict},
+    Answer, Part,
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Value of the `session` cookie of a logged in browser.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Kept next to the cached inputs, so separate runs share one throttle.
+pub const THROTTLE_FILE: &str = ".last-request";
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/crasite/aoc_2023";
+
+/// Spaces requests at least `interval` apart, across processes.
+pub struct Throttle {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl Throttle {
+    pub fn new(path: impl Into<PathBuf>, interval: Duration) -> Self {
+        Self {
+            path: path.into(),
+            interval,
+        }
+    }
+
+    /// Sleeps until `interval` has passed since the last recorded request, then records this one.
+    /// Returns how long it slept.
+    pub fn wait(&self) -> Result<Duration> {
+        let last = match fs::read_to_string(&self.path) {
+            Ok(millis) => millis.trim().parse().ok().map(Duration::from_millis),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => {
+                return Err(err).with_context(|| format!("could not read {}", self.path.display()))
+            }
+        };
+        let delay = last
+            .map(|last| (last + self.interval).saturating_sub(submission::now()))
+            .unwrap_or_default();
+        thread::sleep(delay);
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("could not create {}", dir.display()))?;
+        }
+        fs::write(&self.path, submission::now().as_millis().to_string())
+            .with_context(|| format!("could not write {}", self.path.display()))?;
+        Ok(delay)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        }
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: Option<String>,
+    throttle: Throttle,
+}
+
+impl Client {
+    /// `session` is only needed once something has to be downloaded.
+    pub fn new(base_url: &str, session: Option<String>, throttle: Throttle) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.filter(|session| !session.is_empty()),
+            throttle,
+        }
+    }
+
+    fn cookie(&self) -> Result<String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => bail!("no session token, pass --session or set {}", SESSION_ENV),
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let cookie = self.cookie()?;
+        self.throttle.wait()?;
+        let url = format!("{}{}", self.base_url, path);
+        let response = self.agent.get(&url).header("Cookie", &cookie).call();
+        read_response(&url, response)
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let cookie = self.cookie()?;
+        self.throttle.wait()?;
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &cookie)
+            .send_form(form.iter().copied());
+        read_response(&url, response)
+    }
+
+    /// Returns the cached input under `dir` if there is one, and downloads it there otherwise.
+    pub fn fetch_input(&self, year: u16, day: u8, dir: &Path) -> Result<Fetched> {
+        let path = input::path_in(dir, year, day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let input = self.get(&format!("/{}/day/{}/input", year, day))?;
+        let parent = path.parent().unwrap_or(dir);
+        fs::create_dir_all(parent)
+            .with_context(|| format!("could not create {}", parent.display()))?;
+        // Write next to the target and rename, so an interrupted download is never mistaken for a cached one.
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, input)
+            .and_then(|_| fs::rename(&partial, &path))
+            .with_context(|| format!("could not write {}", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Sends one answer and reads the verdict out of the page that comes back.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<Verdict> {
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer.as_str())];
+        let page = self.post_form(&format!("/{}/day/{}/answer", year, day), &form)?;
+        submission::parse_response(&page)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<String> {
+    response
+        .map_err(|err| request_error(url, err))?
+        .body_mut()
+        .read_to_string()
+        .with_context(|| format!("could not read the response from {}", url))
+}
+
+fn request_error(url: &str, err: ureq::Error) -> anyhow::Error {
+    match err {
+        ureq::Error::StatusCode(400) => {
+            anyhow!(
+                "{} answered 400, the session token is probably invalid",
+                url
+            )
+        }
+        ureq::Error::StatusCode(404) => {
+            anyhow!(
+                "{} answered 404, the puzzle is probably not unlocked yet",
+                url
+            )
+        }
+        ureq::Error::StatusCode(code) => anyhow!("{} answered {}", url, code),
+        err => anyhow::Error::new(err).context(format!("could not reach {}", url)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+        time::Instant,
+    };
+
+    use super::*;
+
+    /// A one-thread HTTP server answering each of `responses` in turn and returning the requests it saw.
+    fn stand_in(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut body_in = vec![0; length];
+                reader.read_exact(&mut body_in).unwrap();
+                request.push_str(&String::from_utf8(body_in).unwrap());
+                requests.push(request);
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn client(url: &str, session: Option<&str>, dir: &Path) -> Client {
+        let throttle = Throttle::new(dir.join(THROTTLE_FILE), Duration::ZERO);
+        Client::new(url, session.map(String::from), throttle)
+    }
+
+    #[test]
+    fn could_fetch_and_cache_input() {
+        let dir = temp_dir("fetch");
+        let (url, server) = stand_in(vec![(200, "1 2\n3 4\n")]);
+        let client = client(&url, Some("abc"), &dir);
+        let path = dir.join("2023/05.txt");
+        let fetched = client.fetch_input(2023, 5, &dir).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        // The stand-in only answers once, so this has to come from the cache.
+        let fetched = client.fetch_input(2023, 5, &dir).unwrap();
+        assert_eq!(fetched, Fetched::Cached(path));
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/5/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("cookie: session=abc\r\n"));
+        assert!(requests[0].contains(USER_AGENT));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn could_refuse_without_session() {
+        let dir = temp_dir("session");
+        let client = client("http://127.0.0.1:9", Some(""), &dir);
+        let err = client.fetch_input(2023, 5, &dir).unwrap_err();
+        assert!(err.to_string().starts_with("no session token"));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn could_report_http_error() {
+        let dir = temp_dir("error");
+        let (url, server) = stand_in(vec![(404, "Not Found")]);
+        let client = client(&url, Some("abc"), &dir);
+        let err = client.fetch_input(2023, 25, &dir).unwrap_err();
+        assert!(err.to_string().contains("answered 404"));
+        assert!(!input::path_in(&dir, 2023, 25).exists());
+        server.join().unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn could_submit_answer() {
+        let dir = temp_dir("submit");
+        let page = "<html><article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article></html>";
+        let (url, server) = stand_in(vec![(200, page)]);
+        let client = client(&url, Some("abc"), &dir);
+        let verdict = client
+            .submit(2023, 5, Part::Two, &Answer::from(42))
+            .unwrap();
+        assert_eq!(verdict.outcome, submission::Outcome::TooLow);
+        assert_eq!(verdict.wait, Some(Duration::from_secs(60)));
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("cookie: session=abc\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=42"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn could_throttle_requests() {
+        let dir = temp_dir("throttle");
+        let throttle = Throttle::new(dir.join(THROTTLE_FILE), Duration::from_millis(200));
+        assert_eq!(throttle.wait().unwrap(), Duration::ZERO);
+        let start = Instant::now();
+        assert!(throttle.wait().unwrap() > Duration::from_millis(100));
+        assert!(start.elapsed() > Duration::from_millis(100));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of the sequence `start, step(start), step(step(start)), ..`: the first
+/// `prefix` states never come back, the `length` states after them repeat forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+    /// Indices below `prefix` whose state is a target.
+    pub prefix_hits: Vec<usize>,
+    /// Indices in `prefix..prefix + length` whose state is a target.
+    pub cycle_hits: Vec<usize>,
+}
+
+impl Cycle {
+    fn new(prefix: usize, length: usize, hits: impl IntoIterator<Item = usize>) -> Self {
+        let (prefix_hits, cycle_hits) = hits.into_iter().partition(|&i| i < prefix);
+        Self {
+            prefix,
+            length,
+            prefix_hits,
+            cycle_hits,
+        }
+    }
+
+    /// Whether the state at index `n` is a target.
+    pub fn is_hit(&self, n: usize) -> bool {
+        if n < self.prefix {
+            self.prefix_hits.contains(&n)
+        } else {
+            let n = self.prefix + (n - self.prefix) % self.length;
+            self.cycle_hits.contains(&n)
+        }
+    }
+
+    /// Every index that is a target, in order; endless unless the cycle has no targets.
+    pub fn hits(&self) -> impl Iterator<Item = usize> + '_ {
+        let repeats = if self.cycle_hits.is_empty() {
+            0
+        } else {
+            usize::MAX
+        };
+        let cycle = (0..repeats).flat_map(move |round| {
+            self.cycle_hits
+                .iter()
+                .map(move |hit| hit + round * self.length)
+        });
+        self.prefix_hits.iter().copied().chain(cycle)
+    }
+}
+
+/// Indices of the targets among the first `count` states.
+fn find_hits<S>(
+    start: S,
+    count: usize,
+    mut step: impl FnMut(&S) -> S,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Vec<usize> {
+    let mut hits = vec![];
+    let mut state = start;
+    for i in 0..count {
+        if is_target(&state) {
+            hits.push(i);
+        }
+        if i + 1 < count {
+            state = step(&state);
+        }
+    }
+    hits
+}
+
+/// Brent's algorithm: constant memory, fewer `step` calls than [`floyd`].
+pub fn brent<S: Clone + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    is_target: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    let hits = find_hits(start, prefix + length, step, is_target);
+    Cycle::new(prefix, length, hits)
+}
+
+/// Floyd's tortoise and hare: constant memory.
+pub fn floyd<S: Clone + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    is_target: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    let hits = find_hits(start, prefix + length, step, is_target);
+    Cycle::new(prefix, length, hits)
+}
+
+/// Remembers every state, so `step` runs only `prefix + length` times.
+pub fn hashed<S: Clone + Hash + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut is_target: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut hits = vec![];
+    let mut state = start;
+    for i in 0.. {
+        if let Some(&first) = seen.get(&state) {
+            return Cycle::new(first, i - first, hits);
+        }
+        if is_target(&state) {
+            hits.push(i);
+        }
+        let next = step(&state);
+        seen.insert(state, i);
+        state = next;
+    }
+    unreachable!("the sequence ran out of indices without repeating")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> 3 -> ..
+    fn step(n: &u32) -> u32 {
+        if *n == 7 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    fn is_even(n: &u32) -> bool {
+        n.is_multiple_of(2)
+    }
+
+    #[test]
+    fn could_detect_cycle() {
+        let expected = Cycle {
+            prefix: 3,
+            length: 5,
+            prefix_hits: vec![0, 2],
+            cycle_hits: vec![4, 6],
+        };
+        assert_eq!(brent(0, step, is_even), expected);
+        assert_eq!(floyd(0, step, is_even), expected);
+        assert_eq!(hashed(0, step, is_even), expected);
+    }
+
+    #[test]
+    fn could_detect_cycle_without_prefix() {
+        let cycle = brent(3, step, |n| *n == 3);
+        assert_eq!((cycle.prefix, cycle.length), (0, 5));
+        assert_eq!(cycle.cycle_hits, vec![0]);
+        assert_eq!(hashed(3, step, |n| *n == 3), cycle);
+        assert_eq!(floyd(3, step, |n| *n == 3), cycle);
+        let fixed = brent(1, |n| *n, |_| false);
+        assert_eq!((fixed.prefix, fixed.length), (0, 1));
+    }
+
+    #[test]
+    fn could_predict_hits() {
+        let cycle = hashed(0, step, is_even);
+        let mut state = 0;
+        for n in 0..40 {
+            assert_eq!(cycle.is_hit(n), is_even(&state), "index {}", n);
+            state = step(&state);
+        }
+        let hits: Vec<_> = cycle.hits().take(6).collect();
+        assert_eq!(hits, vec![0, 2, 4, 6, 9, 11]);
+        assert_eq!(hashed(0, step, |n| *n == 1).hits().count(), 1);
+    }
+}
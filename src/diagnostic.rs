@@ -0,0 +1,181 @@
+use std::fmt::{Formatter, Result as FmtResult};
+
+use nom::Offset;
+use nom_supreme::error::{BaseErrorKind, ErrorTree, StackContext};
+
+use crate::error::{Context, Error, Position};
+
+/// One leaf of an [`ErrorTree`] with the context labels it was reached through.
+struct Leaf<'a> {
+    location: &'a str,
+    expected: String,
+    contexts: Vec<(&'a str, &'static str)>,
+}
+
+fn collect<'a>(
+    tree: &ErrorTree<&'a str>,
+    outer: &[(&'a str, &'static str)],
+    leaves: &mut Vec<Leaf<'a>>,
+) {
+    match tree {
+        ErrorTree::Base { location, kind } => {
+            let expected = match kind {
+                BaseErrorKind::Expected(expectation) => expectation.to_string(),
+                BaseErrorKind::Kind(kind) => kind.description().to_lowercase(),
+                BaseErrorKind::External(e) => e.to_string(),
+            };
+            leaves.push(Leaf {
+                location,
+                expected,
+                contexts: outer.to_vec(),
+            });
+        }
+        ErrorTree::Stack { base, contexts } => {
+            // Stack contexts are ordered innermost first; labels added by plain nom
+            // combinators (`Kind`) are noise next to the ones we wrote ourselves.
+            let mut labels: Vec<_> = contexts
+                .iter()
+                .filter_map(|(location, context)| match context {
+                    StackContext::Context(label) => Some((*location, *label)),
+                    StackContext::Kind(_) => None,
+                })
+                .collect();
+            labels.extend_from_slice(outer);
+            collect(base, &labels, leaves);
+        }
+        ErrorTree::Alt(siblings) => {
+            for sibling in siblings {
+                collect(sibling, outer, leaves);
+            }
+        }
+    }
+}
+
+impl Error {
+    /// Builds an error from the alternative that got furthest into `input`.
+    pub fn from_tree(input: &str, tree: &ErrorTree<&str>) -> Self {
+        let mut leaves = vec![];
+        collect(tree, &[], &mut leaves);
+        let furthest = leaves
+            .iter()
+            .map(|leaf| input.offset(leaf.location))
+            .max()
+            .unwrap_or_default();
+        leaves.retain(|leaf| input.offset(leaf.location) == furthest);
+
+        let mut expected: Vec<&str> = vec![];
+        for leaf in &leaves {
+            if !expected.contains(&leaf.expected.as_str()) {
+                expected.push(&leaf.expected);
+            }
+        }
+        let mut message = match expected.as_slice() {
+            [] => "invalid input".to_string(),
+            [one] => format!("expected {}", one),
+            many => format!("expected one of {}", many.join(", ")),
+        };
+        let contexts: Vec<Context> = leaves
+            .first()
+            .map(|leaf| {
+                leaf.contexts
+                    .iter()
+                    .map(|(location, label)| Context {
+                        label,
+                        position: Position::locate(input, location),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        if let Some(context) = contexts.first() {
+            message.push_str(&format!(" while parsing {}", context.label));
+        }
+
+        let location = leaves.first().map_or(input, |leaf| leaf.location);
+        let position = Position::locate(input, location);
+        let source_line = position.and_then(|position| input.lines().nth(position.line - 1));
+        Self {
+            position,
+            source_line: source_line.map(|line| line.to_string()),
+            contexts,
+            ..Self::new(message)
+        }
+    }
+}
+
+/// Renders the offending line with a caret under the failure, then the context chain.
+pub(crate) fn render(error: &Error, f: &mut Formatter<'_>) -> FmtResult {
+    if let (Some(line), Some(position)) = (&error.source_line, error.position) {
+        let number = position.line.to_string();
+        let gutter = " ".repeat(number.len());
+        write!(f, "\n{} |", gutter)?;
+        write!(f, "\n{} | {}", number, line)?;
+        write!(f, "\n{} | {}^", gutter, " ".repeat(position.column - 1))?;
+        for context in &error.contexts {
+            write!(f, "\n{} = while parsing {}", gutter, context.label)?;
+            if let Some(position) = context.position {
+                write!(
+                    f,
+                    " from line {}, column {}",
+                    position.line, position.column
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{finish, parse_lines, IResult};
+    use nom::{branch::alt, character::complete::u32 as parse_u32, sequence::preceded, Parser};
+    use nom_supreme::{tag::complete::tag, ParserExt};
+
+    fn parse_color(input: &str) -> IResult<'_, &str> {
+        alt((tag("red"), tag("blue"))).context("color").parse(input)
+    }
+
+    fn parse_cube(input: &str) -> IResult<'_, (u32, &str)> {
+        parse_u32
+            .context("count")
+            .terminated(tag(" "))
+            .and(parse_color)
+            .context("cube")
+            .parse(input)
+    }
+
+    #[test]
+    fn could_report_expectation_with_context() {
+        let input = "1 red\n2 green";
+        let err = parse_lines(input, parse_cube).unwrap_err();
+        assert_eq!(
+            err.message,
+            r#"expected one of "red", "blue" while parsing color"#
+        );
+        let labels: Vec<_> = err.contexts.iter().map(|c| c.label).collect();
+        assert_eq!(labels, vec!["color", "cube"]);
+    }
+
+    #[test]
+    fn could_render_caret() {
+        let input = "id 1\nid x";
+        let err = parse_lines(input, preceded(tag("id "), parse_u32.context("id"))).unwrap_err();
+        let expected = [
+            "line 2, column 4: expected an ascii digit while parsing id",
+            "  |",
+            "2 | id x",
+            "  |    ^",
+            "  = while parsing id from line 2, column 4",
+        ];
+        assert_eq!(err.to_string(), expected.join("\n"));
+    }
+
+    #[test]
+    fn could_pick_furthest_alternative() {
+        let input = "ax";
+        let result = alt((preceded(tag("a"), tag("b")), tag("c"))).parse(input);
+        let err = finish(input, result).unwrap_err();
+        assert_eq!(err.position.unwrap().column, 2);
+        assert_eq!(err.message, r#"expected "b""#);
+        assert_eq!(err.source_line.as_deref(), Some("ax"));
+    }
+}
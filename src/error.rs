@@ -0,0 +1,203 @@
+use std::fmt::Display;
+
+use nom::Offset;
+use nom_supreme::error::ErrorTree;
+
+use crate::Part;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// What every day parser returns, so failures can be rendered with their context labels.
+pub type IResult<'a, T> = nom::IResult<&'a str, T, ErrorTree<&'a str>>;
+
+/// 1-based position in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Position of `rest` inside `input`, or `None` if `rest` is not a slice of `input`.
+    pub fn locate(input: &str, rest: &str) -> Option<Self> {
+        let start = input.as_ptr() as usize;
+        let at = rest.as_ptr() as usize;
+        if at < start || at > start + input.len() {
+            return None;
+        }
+        let consumed = &input[..input.offset(rest)];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let column = consumed[line_start..].chars().count() + 1;
+        Some(Self { line, column })
+    }
+}
+
+/// A label attached with `.context(..)` to a parser that was running when parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+    pub label: &'static str,
+    pub position: Option<Position>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    /// `None` while parsing, since the input is parsed once for both parts.
+    pub part: Option<Part>,
+    pub position: Option<Position>,
+    pub message: String,
+    /// The input line `position` points into.
+    pub source_line: Option<String>,
+    /// Innermost first.
+    pub contexts: Vec<Context>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            year: None,
+            day: None,
+            part: None,
+            position: None,
+            message: message.into(),
+            source_line: None,
+            contexts: vec![],
+        }
+    }
+
+    /// An error found at `rest`, which must be a slice of the full puzzle `input`.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let position = Position::locate(input, rest);
+        let source_line = position.and_then(|position| input.lines().nth(position.line - 1));
+        Self {
+            position,
+            source_line: source_line.map(|line| line.to_string()),
+            ..Self::new(message)
+        }
+    }
+
+    pub fn in_day(mut self, year: u16, day: u8) -> Self {
+        self.year.get_or_insert(year);
+        self.day.get_or_insert(day);
+        self
+    }
+
+    pub fn in_part(mut self, part: Part) -> Self {
+        self.part.get_or_insert(part);
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut context = vec![];
+        if let (Some(year), Some(day)) = (self.year, self.day) {
+            match self.part {
+                Some(part) => context.push(format!("{} day {} part {}", year, day, part)),
+                None => context.push(format!("{} day {} input", year, day)),
+            }
+        }
+        if let Some(position) = self.position {
+            context.push(format!(
+                "line {}, column {}",
+                position.line, position.column
+            ));
+        }
+        if !context.is_empty() {
+            write!(f, "{}: ", context.join(", "))?;
+        }
+        f.write_str(&self.message)?;
+        crate::diagnostic::render(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Unwraps a nom result, reporting failures against the full puzzle `input`.
+/// Anything but whitespace left over after parsing is an error too.
+pub fn finish<'a, T>(input: &'a str, result: IResult<'a, T>) -> Result<T> {
+    match result {
+        Ok((rest, value)) if rest.trim().is_empty() => Ok(value),
+        Ok((rest, _)) => Err(Error::at(input, rest.trim_start(), "expected end of input")),
+        Err(nom::Err::Error(tree) | nom::Err::Failure(tree)) => Err(Error::from_tree(input, &tree)),
+        Err(nom::Err::Incomplete(_)) => Err(Error::new("unexpected end of input")),
+    }
+}
+
+/// Runs `parser` on every line of `input`, stopping at the first line that fails.
+/// Trailing blank lines are ignored.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> Result<Vec<T>> {
+    input
+        .trim_end_matches(['\r', '\n'])
+        .lines()
+        .map(|line| finish(input, parser(line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete::u32 as parse_u32, sequence::preceded};
+    use nom_supreme::tag::complete::tag;
+
+    fn parse_id(input: &str) -> IResult<'_, u32> {
+        preceded(tag("id "), parse_u32)(input)
+    }
+
+    #[test]
+    fn could_locate_position() {
+        let input = "abc\ndef\nghi";
+        assert_eq!(
+            Position::locate(input, &input[5..]),
+            Some(Position { line: 2, column: 2 })
+        );
+        assert_eq!(
+            Position::locate(input, &input[11..]),
+            Some(Position { line: 3, column: 4 })
+        );
+        let elsewhere = String::from("abc\ndef\nghi");
+        assert_eq!(Position::locate(input, &elsewhere[5..]), None);
+    }
+
+    #[test]
+    fn could_report_failing_line() {
+        let input = "id 1\nid 2\nid x\nid 4";
+        let err = parse_lines(input, parse_id).unwrap_err();
+        assert_eq!(err.position, Some(Position { line: 3, column: 4 }));
+        assert_eq!(parse_lines("id 1\nid 2", parse_id).unwrap(), vec![1, 2]);
+        assert_eq!(
+            parse_lines("id 1\r\nid 2\r\n\r\n", parse_id).unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn could_reject_trailing_input() {
+        let input = "id 1\nid 2x\n";
+        let err = parse_lines(input, parse_id).unwrap_err();
+        assert_eq!(err.position, Some(Position { line: 2, column: 5 }));
+        assert_eq!(err.message, "expected end of input");
+        assert_eq!(err.source_line.as_deref(), Some("id 2x"));
+        assert_eq!(finish("id 3 \n\n", parse_id("id 3 \n\n")).unwrap(), 3);
+    }
+
+    #[test]
+    fn could_display_context() {
+        let input = "id 1\nid";
+        let mut err = parse_lines(input, parse_id).unwrap_err().in_day(2023, 4);
+        err.source_line = None;
+        assert_eq!(
+            err.to_string(),
+            "2023 day 4 input, line 2, column 1: expected \"id \""
+        );
+        let err = Error::new("no winning move");
+        assert_eq!(err.to_string(), "no winning move");
+        let err = err.in_day(2023, 6).in_part(Part::Two);
+        assert_eq!(err.to_string(), "2023 day 6 part 2: no winning move");
+    }
+}
@@ -0,0 +1,357 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use aoc::rng::Rng;
+
+/// Days with a generator, by year and day.
+pub const SUPPORTED: [(u16, u8); 7] = [
+    (2023, 2),
+    (2023, 3),
+    (2023, 4),
+    (2023, 5),
+    (2023, 7),
+    (2023, 8),
+    (2022, 18),
+];
+
+/// How big an input to make and from which seed. Without a size, inputs come out about as big
+/// as the real ones. What size counts differs per day: games, rows, cards, map entries, hands,
+/// nodes or cubes.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub size: Option<usize>,
+    pub seed: u64,
+}
+
+pub fn generate(year: u16, day: u8, options: Options) -> Result<String> {
+    let mut rng = Rng::new(options.seed);
+    let size = |default| options.size.unwrap_or(default);
+    Ok(match (year, day) {
+        (2023, 2) => games(&mut rng, size(100)),
+        (2023, 3) => schematic(&mut rng, size(140)),
+        (2023, 4) => cards(&mut rng, size(200)),
+        (2023, 5) => almanac(&mut rng, size(30)),
+        (2023, 7) => hands(&mut rng, size(1000))?,
+        (2023, 8) => network(&mut rng, size(750))?,
+        (2022, 18) => droplet(&mut rng, size(2500)),
+        _ => bail!("no generator for {} day {}", year, day),
+    })
+}
+
+/// `size` games of up to six draws; counts go past the 12/13/14 limits now and then.
+fn games(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for game in 1..=size {
+        let draws: Vec<String> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let count = rng.range(1..4) as usize;
+                let cubes: Vec<String> = colors[..count]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.range(1..17), color))
+                    .collect();
+                cubes.join(", ")
+            })
+            .collect();
+        out.push_str(&format!("Game {}: {}\n", game, draws.join("; ")));
+    }
+    out
+}
+
+const SYMBOLS: &[u8] = b"**#+$/@=%&-";
+
+/// A `size` by `size` schematic, with numbers always separated by at least one cell.
+fn schematic(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+        while row.len() < size {
+            let roll = rng.below(100);
+            let digits = rng.range(1..4) as usize;
+            if roll < 12 && row.len() + digits <= size {
+                row.push(b'1' + rng.below(9) as u8);
+                for _ in 1..digits {
+                    row.push(b'0' + rng.below(10) as u8);
+                }
+                if row.len() < size {
+                    row.push(if rng.chance(1, 4) {
+                        *rng.choose(SYMBOLS)
+                    } else {
+                        b'.'
+                    });
+                }
+            } else if roll < 18 {
+                row.push(*rng.choose(SYMBOLS));
+            } else {
+                row.push(b'.');
+            }
+        }
+        out.push_str(std::str::from_utf8(&row).unwrap());
+        out.push('\n');
+    }
+    out
+}
+
+/// `size` cards of 10 winning and 25 drawn numbers. Fewer than one match on average keeps the
+/// copies in part 2 from growing exponentially, and no card wins copies past the last one.
+fn cards(rng: &mut Rng, size: usize) -> String {
+    let width = size.to_string().len();
+    let mut out = String::new();
+    for card in 1..=size {
+        let mut numbers: Vec<u64> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+        let (winning, rest) = numbers.split_at(10);
+        let matches = if rng.chance(6, 10) {
+            0
+        } else {
+            rng.range(1..5) as usize
+        };
+        let matches = matches.min(size - card);
+        let mut drawn: Vec<u64> = winning[..matches].to_vec();
+        drawn.extend_from_slice(&rest[..25 - matches]);
+        rng.shuffle(&mut drawn);
+        let list = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        out.push_str(&format!(
+            "Card {:>width$}: {} | {}\n",
+            card,
+            list(winning),
+            list(&drawn),
+            width = width
+        ));
+    }
+    out
+}
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Five seed ranges and seven maps of `size` entries each, with disjoint sources like the real ones.
+fn almanac(rng: &mut Rng, size: usize) -> String {
+    const SPAN: u64 = 1 << 32;
+    let seeds: Vec<String> = (0..5)
+        .map(|_| format!("{} {}", rng.below(SPAN), rng.range(1..1 << 28)))
+        .collect();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+    for pair in CATEGORIES.windows(2) {
+        out.push_str(&format!("\n{}-to-{} map:\n", pair[0], pair[1]));
+        let mut cuts: Vec<u64> = (0..size.max(1) * 2).map(|_| rng.below(SPAN)).collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+        for source in cuts.chunks_exact(2) {
+            let len = source[1] - source[0] + 1;
+            out.push_str(&format!("{} {} {}\n", rng.below(SPAN), source[0], len));
+        }
+    }
+    out
+}
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// `size` distinct hands, so every ranking is well defined.
+fn hands(rng: &mut Rng, size: usize) -> Result<String> {
+    if size > CARDS.len().pow(5) {
+        bail!("there are only {} distinct hands", CARDS.len().pow(5));
+    }
+    let mut seen = HashSet::new();
+    let mut out = String::new();
+    while seen.len() < size {
+        let hand: Vec<u8> = (0..5).map(|_| *rng.choose(CARDS)).collect();
+        if seen.insert(hand.clone()) {
+            let hand = String::from_utf8(hand).unwrap();
+            out.push_str(&format!("{} {}\n", hand, rng.range(1..1001)));
+        }
+    }
+    Ok(out)
+}
+
+const PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// About `size` nodes shaped like the real network: every ghost starts at a `..A` node and walks
+/// a ring of `prime * instructions` nodes ending at its `..Z` node, whose exits match the start's.
+/// The direction the walk does not take leads somewhere else on the same ring.
+fn network(rng: &mut Rng, size: usize) -> Result<String> {
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    let ghosts = rng.range(1..7) as usize;
+    let primes = &primes[..ghosts];
+    let length = (size as u64 / primes.iter().sum::<u64>()).max(2) as usize;
+    let directions: Vec<char> = (0..length)
+        .map(|_| if rng.chance(1, 2) { 'L' } else { 'R' })
+        .collect();
+    let total: u64 = primes.iter().map(|prime| prime * length as u64).sum();
+    if total > 15_000 {
+        bail!("{} nodes do not fit in three-letter names", total);
+    }
+
+    let mut names = Names::default();
+    let mut lines = vec![];
+    for (ghost, prime) in primes.iter().enumerate() {
+        let (start, end) = if ghost == 0 {
+            names.take("AAA");
+            names.take("ZZZ");
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (names.random(rng, b'A'), names.random(rng, b'Z'))
+        };
+        let ring_len = *prime as usize * length;
+        let mut ring: Vec<String> = (0..ring_len - 1).map(|_| names.inner(rng)).collect();
+        ring.push(end);
+        // Entering ring[i] happens after i + 1 steps, so the step out of it reads direction i + 1.
+        let exits = |rng: &mut Rng, step: usize, next: &str| {
+            let decoy = rng.choose(&ring).clone();
+            match directions[step % length] {
+                'L' => (next.to_string(), decoy),
+                _ => (decoy, next.to_string()),
+            }
+        };
+        let (left, right) = exits(rng, 0, &ring[0]);
+        lines.push(format!("{} = ({}, {})", start, left, right));
+        for i in 0..ring_len {
+            let (left, right) = exits(rng, i + 1, &ring[(i + 1) % ring_len]);
+            lines.push(format!("{} = ({}, {})", ring[i], left, right));
+        }
+    }
+    rng.shuffle(&mut lines);
+    let directions: String = directions.into_iter().collect();
+    Ok(format!("{}\n\n{}\n", directions, lines.join("\n")))
+}
+
+/// Hands out distinct node names; only starts end in `A` and only ends in `Z`.
+#[derive(Default)]
+struct Names {
+    taken: HashSet<String>,
+}
+
+impl Names {
+    fn take(&mut self, name: &str) -> bool {
+        self.taken.insert(name.to_string())
+    }
+
+    fn random(&mut self, rng: &mut Rng, last: u8) -> String {
+        loop {
+            let name: String = [b'A' + rng.below(26) as u8, b'A' + rng.below(26) as u8, last]
+                .into_iter()
+                .map(char::from)
+                .collect();
+            if self.take(&name) {
+                return name;
+            }
+        }
+    }
+
+    fn inner(&mut self, rng: &mut Rng) -> String {
+        let last = b'B' + rng.below(24) as u8;
+        self.random(rng, last)
+    }
+}
+
+/// `size` cubes grown outwards from one, inside a box loose enough to leave air pockets.
+fn droplet(rng: &mut Rng, size: usize) -> String {
+    if size == 0 {
+        return String::new();
+    }
+    let side = ((size as f64 * 2.5).cbrt().ceil() as i64).max(1);
+    let size = size.min((side * side * side) as usize);
+    let centre = side / 2;
+    let mut cubes = vec![(centre, centre, centre)];
+    let mut seen: HashSet<_> = cubes.iter().copied().collect();
+    while cubes.len() < size {
+        let (x, y, z) = *rng.choose(&cubes);
+        let (dx, dy, dz) = *rng.choose(&[
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]);
+        let cube = (x + dx, y + dy, z + dz);
+        let inside = [cube.0, cube.1, cube.2]
+            .iter()
+            .all(|c| (0..side).contains(c));
+        if inside && seen.insert(cube) {
+            cubes.push(cube);
+        }
+    }
+    rng.shuffle(&mut cubes);
+    cubes
+        .iter()
+        .map(|(x, y, z)| format!("{},{},{}\n", x, y, z))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Part;
+
+    use super::*;
+    use crate::registry;
+
+    fn options(size: usize, seed: u64) -> Options {
+        Options {
+            size: Some(size),
+            seed,
+        }
+    }
+
+    #[test]
+    fn could_generate_solvable_inputs() {
+        let puzzles = registry::puzzles();
+        for (year, day) in SUPPORTED {
+            let puzzle = registry::find(&puzzles, year, day).unwrap();
+            for seed in 0..5 {
+                let input = generate(year, day, options(40, seed)).unwrap();
+                if let Err(e) = puzzle.solve(&input, &Part::ALL) {
+                    panic!("{} day {} seed {}: {}\n{}", year, day, seed, e, input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn could_repeat_with_seed() {
+        for (year, day) in SUPPORTED {
+            let first = generate(year, day, options(30, 7)).unwrap();
+            assert_eq!(first, generate(year, day, options(30, 7)).unwrap());
+            assert_ne!(first, generate(year, day, options(30, 8)).unwrap());
+        }
+        assert!(generate(2023, 1, options(30, 7)).is_err());
+    }
+
+    #[test]
+    fn could_generate_empty_droplet() {
+        assert_eq!(generate(2022, 18, options(0, 1)).unwrap(), "");
+        assert_eq!(
+            generate(2022, 18, options(1, 1)).unwrap().lines().count(),
+            1
+        );
+    }
+
+    #[test]
+    fn could_build_network_with_known_answers() {
+        let puzzles = registry::puzzles();
+        let puzzle = registry::find(&puzzles, 2023, 8).unwrap();
+        let input = generate(2023, 8, options(200, 3)).unwrap();
+        let length = input.lines().next().unwrap().len() as u64;
+        let answers = puzzle.solve(&input, &Part::ALL).unwrap();
+        // Every walk is a whole number of passes over the instructions.
+        for (_, answer) in answers {
+            assert_eq!(answer.as_str().parse::<u64>().unwrap() % length, 0);
+        }
+    }
+}
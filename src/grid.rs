@@ -0,0 +1,290 @@
+use std::ops::{Index, IndexMut};
+
+use nom::{character::complete::multispace0, sequence::terminated, Parser};
+use nom_supreme::ParserExt;
+
+use crate::{
+    error::{finish, Result},
+    parse,
+};
+
+/// `(x, y)`, with `y` growing downwards like the lines of the puzzle input.
+pub type Point = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular block of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// One row per line; every line must be as wide as the first.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Like [`Grid::parse`], mapping each character with `cell`, which returns `None` to reject it.
+    pub fn parse_with(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut parser = terminated(parse::grid(cell), multispace0).all_consuming();
+        finish(input, parser.parse(input))
+    }
+
+    /// A grid `width` cells wide, or `None` if `cells` do not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return cells.is_empty().then_some(Self {
+                width,
+                height: 0,
+                cells,
+            });
+        }
+        cells.len().is_multiple_of(width).then(|| Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.1 * self.width + point.0])
+        } else {
+            None
+        }
+    }
+
+    /// Every point, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offsets<'a>(
+        &self,
+        (x, y): Point,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx).filter(|x| *x < width)?;
+            let y = y.checked_add_signed(*dy).filter(|y| *y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// Up, right, down and left of `point`, skipping those outside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.offsets(point, &NEIGHBOURS_4)
+    }
+
+    /// Like [`Grid::neighbours4`], diagonals included.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.offsets(point, &NEIGHBOURS_8)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, and a grid without columns has no rows worth yielding.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells[x..].iter().step_by(self.width))
+    }
+
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|point| self[source(point)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside a {}x{} grid",
+                point, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", point, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Position;
+
+    fn rows(grid: &Grid<char>) -> Vec<String> {
+        grid.rows().map(|row| row.iter().collect()).collect()
+    }
+
+    #[test]
+    fn could_parse_grid() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(2).unwrap().collect::<String>(), "cf");
+        assert!(grid.column(3).is_none());
+    }
+
+    #[test]
+    fn could_reject_ragged_rows() {
+        let err = Grid::parse("abc\nde\nfgh").unwrap_err();
+        assert_eq!(err.position, Some(Position { line: 2, column: 3 }));
+        let err =
+            Grid::parse_with("1.\n2x", |c| c.to_digit(10).or((c == '.').then_some(0))).unwrap_err();
+        assert_eq!(err.position, Some(Position { line: 2, column: 2 }));
+    }
+
+    #[test]
+    fn could_find_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn could_transpose_and_rotate() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        assert_eq!(rows(&grid.transpose()), vec!["ad", "be", "cf"]);
+        assert_eq!(rows(&grid.rotate_clockwise()), vec!["da", "eb", "fc"]);
+        assert_eq!(
+            rows(&grid.rotate_counterclockwise()),
+            vec!["cf", "be", "ad"]
+        );
+        let turned = grid
+            .rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise();
+        assert_eq!(turned, grid.rotate_counterclockwise());
+        assert_eq!(Grid::from_vec(2, vec![1, 2, 3]), None);
+        assert_eq!(
+            Grid::from_vec(0, Vec::<u8>::new()).map(|g| g.height()),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn could_update_cells() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid[(1, 0)] = '#';
+        *grid.get_mut((0, 1)).unwrap() = '#';
+        assert_eq!(rows(&grid), vec![".#", "#."]);
+        assert_eq!(grid.iter().filter(|(_, c)| **c == '#').count(), 2);
+    }
+}
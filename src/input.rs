@@ -0,0 +1,195 @@
+use std::{
+    borrow::Cow,
+    env, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::Solver;
+
+/// Path of the input file, `-` for stdin. `{year}` and `{day}` are filled in per puzzle.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+pub const INPUT_DIR: &str = "inputs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `--input` wins over `AOC_INPUT`, which wins over `inputs/<year>/<day>.txt`.
+    pub fn resolve(flag: Option<&str>, year: u16, day: u8) -> Self {
+        Self::resolve_with(flag, env::var(INPUT_ENV).ok(), year, day)
+    }
+
+    fn resolve_with(flag: Option<&str>, env: Option<String>, year: u16, day: u8) -> Self {
+        match flag.or(env.as_deref().filter(|v| !v.is_empty())) {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(expand(path, year, day))),
+            None => Source::File(default_path(year, day)),
+        }
+    }
+
+    /// Reads the input and [`normalize`]s it.
+    pub fn read(&self) -> Result<String> {
+        let input = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("could not read input from stdin")?;
+                input
+            }
+            Source::File(path) => fs::read_to_string(path).with_context(|| {
+                format!(
+                    "could not read input from {} (pass --input <path> or set {})",
+                    path.display(),
+                    INPUT_ENV
+                )
+            })?,
+        };
+        Ok(normalize(&input).into_owned())
+    }
+
+    /// Opens the input for [`crate::stream`]ing instead of reading it whole.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => {
+                let file = fs::File::open(path).with_context(|| {
+                    format!(
+                        "could not open input {} (pass --input <path> or set {})",
+                        path.display(),
+                        INPUT_ENV
+                    )
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+/// Drops a leading byte order mark, turns CRLF into LF and trims trailing whitespace,
+/// so inputs saved on Windows or with a trailing blank line parse like any other.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input).trim_end();
+    if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    path_in(Path::new(INPUT_DIR), year, day)
+}
+
+/// Where a day's input lives under `dir`, laid out like [`INPUT_DIR`].
+pub fn path_in(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("{:02}.txt", day))
+}
+
+fn expand(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{:02}", day))
+}
+
+/// Picks `--input <path>` (or `--input=<path>`) out of a day binary's arguments.
+pub fn input_flag(mut args: impl Iterator<Item = String>) -> Result<Option<String>> {
+    let mut flag = None;
+    while let Some(arg) = args.next() {
+        if arg == "--input" {
+            match args.next() {
+                Some(path) => flag = Some(path),
+                None => bail!("--input needs a path (or - for stdin)"),
+            }
+        } else if let Some(path) = arg.strip_prefix("--input=") {
+            flag = Some(path.to_string());
+        } else {
+            bail!("unexpected argument {:?}, usage: [--input <path>]", arg);
+        }
+    }
+    Ok(flag)
+}
+
+/// Loads the input for a day binary.
+pub fn from_args<S: Solver>() -> Result<String> {
+    let flag = input_flag(env::args().skip(1))?;
+    Source::resolve(flag.as_deref(), S::YEAR, S::DAY).read()
+}
+
+/// Opens the input for a day binary that streams it.
+pub fn reader_from_args<S: Solver>() -> Result<Box<dyn BufRead>> {
+    let flag = input_flag(env::args().skip(1))?;
+    Source::resolve(flag.as_deref(), S::YEAR, S::DAY).open()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn could_resolve_default_path() {
+        let source = Source::resolve_with(None, None, 2023, 5);
+        assert_eq!(source, Source::File(PathBuf::from("inputs/2023/05.txt")));
+    }
+
+    #[test]
+    fn could_prefer_flag_over_env() {
+        let env = Some("env.txt".to_string());
+        let source = Source::resolve_with(Some("flag.txt"), env.clone(), 2023, 5);
+        assert_eq!(source, Source::File(PathBuf::from("flag.txt")));
+        let source = Source::resolve_with(None, env, 2023, 5);
+        assert_eq!(source, Source::File(PathBuf::from("env.txt")));
+        let source = Source::resolve_with(None, Some(String::new()), 2023, 5);
+        assert_eq!(source, Source::File(default_path(2023, 5)));
+    }
+
+    #[test]
+    fn could_resolve_stdin() {
+        assert_eq!(
+            Source::resolve_with(Some("-"), None, 2023, 5),
+            Source::Stdin
+        );
+        let env = Some("-".to_string());
+        assert_eq!(Source::resolve_with(None, env, 2023, 5), Source::Stdin);
+    }
+
+    #[test]
+    fn could_expand_template() {
+        let env = Some("other/{year}/day{day}.txt".to_string());
+        let source = Source::resolve_with(None, env, 2022, 18);
+        assert_eq!(source, Source::File(PathBuf::from("other/2022/day18.txt")));
+    }
+
+    #[test]
+    fn could_normalize_input() {
+        assert_eq!(normalize("a\nb\n"), "a\nb");
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed(_)));
+        assert_eq!(normalize("\u{feff}a\r\n\r\nb\r\n\r\n  \n"), "a\n\nb");
+        assert_eq!(normalize("a  \nb"), "a  \nb");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn could_parse_input_flag() {
+        assert_eq!(input_flag(args(&[])).unwrap(), None);
+        let flag = input_flag(args(&["--input", "a.txt"])).unwrap();
+        assert_eq!(flag.as_deref(), Some("a.txt"));
+        let flag = input_flag(args(&["--input=-"])).unwrap();
+        assert_eq!(flag.as_deref(), Some("-"));
+        assert!(input_flag(args(&["--input"])).is_err());
+        assert!(input_flag(args(&["--verbose"])).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use std::fmt::Display;
+
+/// The integers `start..=end`; never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "empty interval {}..={}", start, end);
+        Self { start, end }
+    }
+
+    /// `len` integers from `start`, or `None` when `len` is not positive.
+    pub fn with_len(start: i64, len: i64) -> Option<Self> {
+        (len > 0).then(|| Self::new(start, start + len - 1))
+    }
+
+    #[allow(clippy::len_without_is_empty)] // an `Interval` is never empty
+    pub fn len(&self) -> u64 {
+        self.end.abs_diff(self.start) + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// The parts of `self` before and after `other`.
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if self.end < other.start {
+            return (Some(*self), None);
+        }
+        if other.end < self.start {
+            return (None, Some(*self));
+        }
+        let before = (self.start < other.start).then(|| Self::new(self.start, other.start - 1));
+        let after = (other.end < self.end).then(|| Self::new(other.end + 1, self.end));
+        (before, after)
+    }
+
+    pub fn shift(&self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// A set of integers kept as sorted, disjoint and non-adjacent intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        self.intervals.push(interval);
+        self.normalize();
+    }
+
+    /// Sorts and merges overlapping or touching intervals.
+    fn normalize(&mut self) {
+        self.intervals.sort();
+        let mut merged: Vec<Interval> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end.saturating_add(1) => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => merged.push(interval),
+            }
+        }
+        self.intervals = merged;
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut rest = self.intervals.clone();
+        for removed in &other.intervals {
+            rest = rest
+                .iter()
+                .flat_map(|interval| {
+                    let (before, after) = interval.difference(removed);
+                    before.into_iter().chain(after)
+                })
+                .collect();
+        }
+        Self { intervals: rest }
+    }
+
+    pub fn shift(&self, offset: i64) -> Self {
+        Self {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|interval| interval.shift(offset))
+                .collect(),
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = Self {
+            intervals: iter.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self {
+            intervals: vec![interval],
+        }
+    }
+}
+
+/// Moves values by a per-segment offset, leaving values outside every segment alone.
+/// When segments overlap, the one inserted first wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    segments: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        self.segments.push((source, offset));
+    }
+
+    pub fn segments(&self) -> &[(Interval, i64)] {
+        &self.segments
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        self.segments
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    /// Where every value of `set` ends up.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut unmapped = set.clone();
+        let mut mapped = vec![];
+        for (source, offset) in &self.segments {
+            let source = IntervalSet::from(*source);
+            mapped.extend(unmapped.intersection(&source).shift(*offset).intervals);
+            unmapped = unmapped.difference(&source);
+        }
+        mapped.extend(unmapped.intervals);
+        mapped.into_iter().collect()
+    }
+}
+
+impl FromIterator<(Interval, i64)> for RangeMap {
+    fn from_iter<I: IntoIterator<Item = (Interval, i64)>>(iter: I) -> Self {
+        Self {
+            segments: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn could_split_interval() {
+        let a = Interval::new(1, 10);
+        assert_eq!(a.len(), 10);
+        assert_eq!(Interval::with_len(5, 0), None);
+        assert_eq!(
+            a.intersection(&Interval::new(8, 20)),
+            Some(Interval::new(8, 10))
+        );
+        assert_eq!(a.intersection(&Interval::new(11, 20)), None);
+        assert_eq!(
+            a.difference(&Interval::new(3, 4)),
+            (Some(Interval::new(1, 2)), Some(Interval::new(5, 10)))
+        );
+        assert_eq!(a.difference(&Interval::new(0, 20)), (None, None));
+        assert_eq!(a.difference(&Interval::new(20, 30)), (Some(a), None));
+        assert_eq!(a.difference(&Interval::new(-5, 0)), (None, Some(a)));
+    }
+
+    #[test]
+    fn could_normalize_set() {
+        let merged = set(&[(5, 8), (1, 3), (4, 4), (10, 12), (11, 15)]);
+        assert_eq!(merged.intervals(), set(&[(1, 8), (10, 15)]).intervals());
+        assert_eq!(merged.len(), 14);
+        assert_eq!((merged.min(), merged.max()), (Some(1), Some(15)));
+        assert!(merged.contains(10) && !merged.contains(9));
+        let mut inserted = merged.clone();
+        inserted.insert(Interval::new(9, 9));
+        assert_eq!(inserted, set(&[(1, 15)]));
+    }
+
+    #[test]
+    fn could_combine_sets() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.union(&b), set(&[(1, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(1, 4), (26, 30)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19)]));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn could_map_values_and_sets() {
+        let map: RangeMap = [(Interval::new(98, 99), -48), (Interval::new(50, 97), 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(10), 10);
+        let mapped = map.map_set(&set(&[(45, 55), (97, 99)]));
+        assert_eq!(mapped, set(&[(45, 51), (52, 57), (99, 99)]));
+        let brute: IntervalSet = (45..=55)
+            .chain(97..=99)
+            .map(|v| Interval::new(map.get(v), map.get(v)))
+            .collect();
+        assert_eq!(mapped, brute);
+    }
+}
@@ -1,3 +1,62 @@
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod cycle;
+pub mod diagnostic;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod json;
+pub mod parse;
+pub mod render;
+pub mod rng;
+pub mod solver;
+pub mod stream;
+pub mod submission;
+pub mod trace;
+pub mod voxel;
+pub mod y2022;
+pub mod y2023;
+
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use solver::{Answer, Part, Puzzle, Solver};
+
+/// Generates one `#[test]` per name, solving every listed sample for that part with the
+/// given [`Solver`]. A sample is either `file "sample.txt"`, read relative to the calling
+/// file, or any `&str` expression:
+///
+/// ```ignore
+/// aoc::sample_tests!(Day8 {
+///     could_solve_part1: One {
+///         file "sample.txt" => 2,
+///         "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)" => 6,
+///     }
+///     could_solve_part2: Two { file "sample2.txt" => 6 }
+/// });
+/// ```
+#[macro_export]
+macro_rules! sample_tests {
+    ($solver:ty { $($name:ident: $part:ident { $($samples:tt)* })* }) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::sample_tests!(@check $solver, $crate::Part::$part, $($samples)*);
+            }
+        )*
+    };
+    (@check $solver:ty, $part:expr, file $path:literal => $expected:expr $(, $($rest:tt)*)?) => {
+        $crate::solver::assert_sample::<$solver>(include_str!($path), $part, $expected);
+        $($crate::sample_tests!(@check $solver, $part, $($rest)*);)?
+    };
+    (@check $solver:ty, $part:expr, $input:expr => $expected:expr $(, $($rest:tt)*)?) => {
+        $crate::solver::assert_sample::<$solver>($input, $part, $expected);
+        $($crate::sample_tests!(@check $solver, $part, $($rest)*);)?
+    };
+    (@check $solver:ty, $part:expr,) => {};
+}
+
 use nom::{
     character::complete::space0, error::ParseError, sequence::delimited, AsChar, IResult,
     InputTakeAtPosition, Parser,
@@ -17,6 +76,35 @@ mod tests {
     use super::*;
     use nom::error::VerboseError;
 
+    struct Lines;
+
+    impl Solver for Lines {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+            Ok(input.len().into())
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+            Ok(input.concat().into())
+        }
+    }
+
+    sample_tests!(Lines {
+        could_count_lines: One {
+            "a\nb" => 2,
+            &"x\n".repeat(3) => 3,
+        }
+        could_join_lines: Two { "a\nb" => "ab" }
+    });
+
     #[test]
     fn space0_surrounded_test() {
         let input = "  123    ";
@@ -1,3 +1,553 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    env,
+    io::{self, IsTerminal},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+use aoc::{
+    answers::{self, ANSWERS_FILE},
+    bench::Options,
+    client::{
+        Client, Fetched, Throttle, BASE_URL, BASE_URL_ENV, DEFAULT_THROTTLE, SESSION_ENV,
+        THROTTLE_FILE,
+    },
+    input::{Source, INPUT_DIR},
+    render::{self, Mode},
+    submission::{self, History, Outcome, Submission, SUBMISSIONS_FILE},
+    Answer, Part, Puzzle,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+use report::{Format, Record};
+
+mod generate;
+mod registry;
+mod report;
+mod scaffold;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one puzzle, or every registered puzzle with --all
+    Run(RunArgs),
+    /// Time parsing and each part separately
+    Bench(BenchArgs),
+    /// Check every registered puzzle against the answers recorded for its stored input
+    Verify(VerifyArgs),
+    /// Create and register a new day from a template
+    New(NewArgs),
+    /// Download a puzzle input into inputs/<year>/<day>.txt unless it is already there
+    Fetch(FetchArgs),
+    /// Send an answer, unless earlier submissions already rule it out
+    Submit(SubmitArgs),
+    /// Print a random input in a day's format
+    Gen(GenArgs),
+}
+
+/// Which puzzles, parts and input a command works on.
+#[derive(Args)]
+struct Target {
+    #[arg(required_unless_present = "all")]
+    year: Option<u16>,
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only solve this part (1 or 2)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    #[arg(long, conflicts_with_all = ["year", "day"])]
+    all: bool,
+    /// Read the input from this file (- for stdin) instead of inputs/<year>/<day>.txt
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+}
+
+impl Target {
+    fn parts(&self) -> Vec<Part> {
+        match self.part.and_then(Part::from_number) {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    fn select<'a>(&self, puzzles: &'a [Puzzle]) -> Result<Vec<&'a Puzzle>> {
+        if self.all {
+            return Ok(puzzles.iter().collect());
+        }
+        let (year, day) = (self.year.unwrap(), self.day.unwrap());
+        match registry::find(puzzles, year, day) {
+            Some(puzzle) => Ok(vec![puzzle]),
+            None => bail!("no solver registered for {} day {}", year, day),
+        }
+    }
+
+    fn read_input(&self, puzzle: &Puzzle) -> Result<String> {
+        Source::resolve(self.input.as_deref(), puzzle.year, puzzle.day).read()
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    target: Target,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Solve up to this many parts at once
+    #[arg(long, short = 'j', default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: u64,
+    /// Draw the parsed input first, for the days that can
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "auto", conflicts_with = "format")]
+    visualize: Option<Colors>,
+    /// Print what the solvers did with each input line first; json prints only that
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "text",
+        conflicts_with_all = ["format", "visualize"]
+    )]
+    explain: Option<Explain>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Explain {
+    Text,
+    /// One object per event, in place of the answers
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Colors {
+    /// Colours when printing to a terminal and NO_COLOR is unset
+    Auto,
+    Ansi,
+    Plain,
+}
+
+impl Colors {
+    fn mode(self) -> Mode {
+        match self {
+            Colors::Ansi => Mode::Ansi,
+            Colors::Plain => Mode::Plain,
+            Colors::Auto if io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() => {
+                Mode::Ansi
+            }
+            Colors::Auto => Mode::Plain,
+        }
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    target: Target,
+    /// Timed runs per stage
+    #[arg(long, short = 'n', default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+    /// Untimed runs per stage before measuring
+    #[arg(long, default_value_t = 10)]
+    warmup: u64,
+    /// Print one JSON object per stage instead of a table
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[arg(long, default_value = ANSWERS_FILE)]
+    answers: String,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+/// How to reach the Advent of Code site.
+#[derive(Args)]
+struct ClientArgs {
+    /// Session cookie of a logged in browser
+    #[arg(long, env = SESSION_ENV, hide_env_values = true)]
+    session: Option<String>,
+    #[arg(long, env = BASE_URL_ENV, default_value = BASE_URL)]
+    base_url: String,
+    /// Minimum seconds between requests
+    #[arg(long, default_value_t = DEFAULT_THROTTLE.as_secs())]
+    throttle: u64,
+}
+
+impl ClientArgs {
+    fn client(&self) -> Client {
+        let throttle = Throttle::new(
+            Path::new(INPUT_DIR).join(THROTTLE_FILE),
+            Duration::from_secs(self.throttle),
+        );
+        Client::new(&self.base_url, self.session.clone(), throttle)
+    }
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Solve the part with the registered solver when left out
+    answer: Option<String>,
+    /// Read the input from this file (- for stdin) when solving
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<String>,
+    /// Send it even if earlier submissions rule it out
+    #[arg(long)]
+    force: bool,
+    #[command(flatten)]
+    client: ClientArgs,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Only needed if the day exists in more than one year
+    #[arg(long)]
+    year: Option<u16>,
+    /// Roughly how big, in the day's own unit (games, rows, cards, ...); about a real input by default
+    #[arg(long, short = 'n')]
+    size: Option<usize>,
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::New(args) => new(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Gen(args) => gen(args),
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let target = args.target;
+    let parts = target.parts();
+    let puzzles = registry::puzzles();
+    let inputs: Vec<_> = target
+        .select(&puzzles)?
+        .into_iter()
+        .map(|puzzle| {
+            (
+                puzzle,
+                target.read_input(puzzle).map_err(|e| format!("{:#}", e)),
+            )
+        })
+        .collect();
+    if let Some(colors) = args.visualize {
+        for (puzzle, input) in &inputs {
+            if let Ok(input) = input {
+                visualize(puzzle, input, colors.mode());
+            }
+        }
+    }
+    if let Some(format) = args.explain {
+        let mut failed = 0;
+        for (puzzle, input) in &inputs {
+            let explained = match input {
+                Ok(input) => explain(puzzle, input, &parts, format),
+                Err(message) => Err(anyhow::anyhow!("{}", message)),
+            };
+            if let Err(e) = explained {
+                failed += 1;
+                eprintln!(
+                    "could not explain {} day {}: {:#}",
+                    puzzle.year, puzzle.day, e
+                );
+            }
+        }
+        if format == Explain::Json {
+            if failed > 0 {
+                bail!("{} of {} days could not be explained", failed, inputs.len());
+            }
+            return Ok(());
+        }
+    }
+    let tasks: Vec<_> = inputs
+        .iter()
+        .flat_map(|(puzzle, input)| parts.iter().map(move |&part| (*puzzle, input, part)))
+        .collect();
+    let start = Instant::now();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(args.jobs as usize)
+        .build()?;
+    let records: Vec<Record> = pool.install(|| {
+        tasks
+            .par_iter()
+            .map(|(puzzle, input, part)| match input {
+                Ok(input) => Record::solve(puzzle, input, *part),
+                Err(message) => Record::failed(puzzle, &[*part], message.clone()).remove(0),
+            })
+            .collect()
+    });
+    print!("{}", report::render(args.format, &records));
+    if target.all && args.format == Format::Text {
+        println!();
+        print!("{}", report::summary(&records, start.elapsed()));
+    }
+    let failed = records
+        .iter()
+        .filter(|record| record.status.error().is_some())
+        .count();
+    if failed > 0 {
+        bail!("{} of {} parts failed", failed, records.len());
+    }
+    Ok(())
+}
+
+/// Failing to draw is reported but never stops the run; solving will report the same problem.
+fn visualize(puzzle: &Puzzle, input: &str, mode: Mode) {
+    match puzzle.visualize(input) {
+        Ok(frames) if frames.is_empty() => {
+            eprintln!("{} day {} has nothing to draw", puzzle.year, puzzle.day)
+        }
+        Ok(frames) => println!("{}", render::render_frames(&frames, mode)),
+        Err(e) => eprintln!("could not draw {} day {}: {}", puzzle.year, puzzle.day, e),
+    }
+}
+
+fn explain(puzzle: &Puzzle, input: &str, parts: &[Part], format: Explain) -> Result<()> {
+    let stages = puzzle.explain(input, parts)?;
+    if stages.iter().all(|(_, events)| events.is_empty()) {
+        eprintln!("{} day {} has nothing to explain", puzzle.year, puzzle.day);
+        return Ok(());
+    }
+    for (stage, events) in stages.iter().filter(|(_, events)| !events.is_empty()) {
+        match format {
+            Explain::Text => {
+                println!("{} day {} {}", puzzle.year, puzzle.day, stage);
+                for event in events {
+                    println!("  {}", event);
+                }
+            }
+            Explain::Json => {
+                let context = format!(
+                    r#""year":{},"day":{},"stage":"{}""#,
+                    puzzle.year, puzzle.day, stage
+                );
+                for event in events {
+                    println!("{}", event.to_json(&context));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<()> {
+    let target = args.target;
+    let parts = target.parts();
+    let options = Options {
+        warmup: args.warmup as usize,
+        iterations: args.iterations as usize,
+    };
+    let puzzles = registry::puzzles();
+    for puzzle in target.select(&puzzles)? {
+        let input = target.read_input(puzzle)?;
+        let timings = puzzle.bench(&input, &parts, options)?;
+        if args.json {
+            for (stage, stats) in timings {
+                println!("{}", stats.to_json(puzzle.year, puzzle.day, stage));
+            }
+            continue;
+        }
+        println!(
+            "{} day {} ({} iterations)",
+            puzzle.year, puzzle.day, options.iterations
+        );
+        for (stage, stats) in timings {
+            println!(
+                "  {:<6}  min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}",
+                stage.to_string(),
+                stats.min,
+                stats.median,
+                stats.p95
+            );
+        }
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    let puzzles = registry::puzzles();
+    let answers = answers::load(&args.answers)?;
+    let checks = verify::check(&puzzles, &answers);
+    let mut failures = 0;
+    for check in &checks {
+        let expected = check.expected;
+        print!(
+            "{} day {} part {}",
+            expected.year, expected.day, expected.part
+        );
+        if let Some(id) = &expected.input {
+            print!(" ({})", id);
+        }
+        match &check.status {
+            verify::Status::Ok => println!(": ok"),
+            verify::Status::Mismatch(answer) => {
+                failures += 1;
+                println!(": expected {}, got {}", expected.answer, answer);
+            }
+            verify::Status::Failed(e) => {
+                failures += 1;
+                println!(": {}", e);
+            }
+        }
+    }
+    for (year, day) in verify::unchecked(&puzzles, &answers) {
+        println!(
+            "{} day {}: no answers recorded in {}",
+            year, day, args.answers
+        );
+    }
+    if failures > 0 {
+        bail!("{} of {} answers did not match", failures, checks.len());
+    }
+    Ok(())
+}
+
+fn new(args: NewArgs) -> Result<()> {
+    let scaffold = scaffold::Scaffold::new(env!("CARGO_MANIFEST_DIR"), args.year, args.day);
+    for path in scaffold.create()? {
+        println!("wrote {}", path.display());
+    }
+    println!(
+        "fill in the sample and its answers, then record the real answers in {}",
+        ANSWERS_FILE
+    );
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<()> {
+    let client = args.client.client();
+    match client.fetch_input(args.year, args.day, Path::new(INPUT_DIR))? {
+        Fetched::Cached(path) => println!("{} is already cached", path.display()),
+        Fetched::Downloaded(path) => println!("saved {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<()> {
+    let (year, day) = (args.year, args.day);
+    let part = Part::from_number(args.part).unwrap();
+    let answer = match args.answer {
+        Some(answer) => Answer::from(answer),
+        None => {
+            let puzzles = registry::puzzles();
+            let Some(puzzle) = registry::find(&puzzles, year, day) else {
+                bail!(
+                    "no solver registered for {} day {}, pass the answer instead",
+                    year,
+                    day
+                );
+            };
+            let input = Source::resolve(args.input.as_deref(), year, day).read()?;
+            puzzle.solve(&input, &[part])?.remove(0).1
+        }
+    };
+    submission::check_answer(&answer)?;
+
+    let path = Path::new(INPUT_DIR).join(SUBMISSIONS_FILE);
+    let mut history = History::load(&path)?;
+    let now = submission::now().as_secs();
+    if let Some(refusal) = history.check(year, day, part, &answer, now) {
+        if !args.force {
+            bail!(
+                "not submitting {}: {} (--force sends it anyway)",
+                answer,
+                refusal
+            );
+        }
+    }
+    let verdict = args.client.client().submit(year, day, part, &answer)?;
+    if !verdict.outcome.is_transient() {
+        history.record(
+            &path,
+            Submission {
+                year,
+                day,
+                part,
+                answer: answer.clone(),
+                outcome: verdict.outcome,
+                retry_at: verdict.wait.map(|wait| now + wait.as_secs()),
+            },
+        )?;
+    }
+
+    match verdict.outcome {
+        Outcome::Correct => println!(
+            "{} is correct, record it in {} as `{} {} {} {}`",
+            answer, ANSWERS_FILE, year, day, part, answer
+        ),
+        Outcome::TooHigh => println!("{} is too high", answer),
+        Outcome::TooLow => println!("{} is too low", answer),
+        Outcome::Incorrect => println!("{} is not the right answer", answer),
+        Outcome::Wait => println!("{} was sent too soon and was not judged", answer),
+        Outcome::WrongLevel => println!(
+            "the site is not asking for part {}: it is already solved, or part 1 is still open",
+            part
+        ),
+    }
+    if let Some(wait) = verdict.wait {
+        println!(
+            "the next answer for day {} can be sent in {}s",
+            day,
+            wait.as_secs()
+        );
+    }
+    Ok(())
+}
+
+fn gen(args: GenArgs) -> Result<()> {
+    let years: Vec<u16> = generate::SUPPORTED
+        .iter()
+        .filter(|&&(year, day)| day == args.day && args.year.is_none_or(|y| y == year))
+        .map(|&(year, _)| year)
+        .collect();
+    let year = match years[..] {
+        [year] => year,
+        [] => bail!("no generator for day {}", args.day),
+        _ => bail!(
+            "day {} exists in {:?}, pick one with --year",
+            args.day,
+            years
+        ),
+    };
+    let options = generate::Options {
+        size: args.size,
+        seed: args.seed,
+    };
+    print!("{}", generate::generate(year, args.day, options)?);
+    Ok(())
 }
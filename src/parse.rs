@@ -0,0 +1,156 @@
+use nom::{
+    character::complete::{line_ending, space0, space1},
+    combinator::recognize,
+    error::{ErrorKind, FromExternalError},
+    multi::separated_list1,
+    sequence::{terminated, tuple},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag};
+
+use crate::{error::IResult, Grid};
+
+/// Numbers separated by spaces, like `79 14 55 13`; trailing spaces are consumed too.
+pub fn numbers<'a, O>(
+    number: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    terminated(separated_list1(space1, number), space0)
+}
+
+/// `key:` followed by `value`, like `Time:      7  15   30`.
+pub fn header<'a, O>(
+    key: &'static str,
+    value: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, O> {
+    let mut label = tuple((tag(key), tag(":"), space0));
+    let mut value = value;
+    move |input| {
+        let (input, _) = label.parse(input)?;
+        value.parse(input)
+    }
+}
+
+/// The empty line between two blocks of input, with either line ending.
+pub fn blank_line(input: &str) -> IResult<'_, &str> {
+    recognize(tuple((line_ending, line_ending)))(input)
+}
+
+/// Where the first [`blank_line`] in `input` starts.
+fn block_end(input: &str) -> usize {
+    let lf = input.find("\n\n");
+    let crlf = input.find("\r\n\r\n");
+    lf.into_iter().chain(crlf).min().unwrap_or(input.len())
+}
+
+/// Blocks of lines separated by [`blank_line`]s, each parsed by `block`.
+pub fn blocks<'a, O>(
+    block: impl Parser<&'a str, O, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated_list1(blank_line, block)
+}
+
+fn failure(location: &str, expected: String) -> nom::Err<ErrorTree<&str>> {
+    nom::Err::Failure(ErrorTree::from_external_error(
+        location,
+        ErrorKind::Verify,
+        expected,
+    ))
+}
+
+/// A block of equally wide lines, stopping at a [`blank_line`] or the end of the input.
+/// `cell` maps each character and returns `None` to reject it.
+pub fn grid<'a, T>(
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Grid<T>> {
+    move |input: &'a str| {
+        let (block, rest) = input.split_at(block_end(input));
+        let mut width = None;
+        let mut cells = vec![];
+        for line in block.lines() {
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(failure(&line[i..], format!("a grid cell, not {:?}", c))),
+                }
+                row_width += 1;
+            }
+            match width {
+                Some(width) if width != row_width => {
+                    let at = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(i, _)| i);
+                    return Err(failure(&line[at..], format!("a row of width {}", width)));
+                }
+                _ => width = Some(row_width),
+            }
+        }
+        let grid =
+            Grid::from_vec(width.unwrap_or_default(), cells).expect("rows have equal widths");
+        Ok((rest, grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::finish;
+    use nom::character::complete::{i64 as parse_i64, newline, u32 as parse_u32};
+
+    #[test]
+    fn could_parse_numbers() {
+        assert_eq!(
+            numbers(parse_u32)("41 48  83 | 1").unwrap(),
+            ("| 1", vec![41, 48, 83])
+        );
+        assert_eq!(numbers(parse_i64)("-1 2\n3").unwrap(), ("\n3", vec![-1, 2]));
+        assert!(numbers(parse_u32)("x").is_err());
+    }
+
+    #[test]
+    fn could_parse_headers() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        let (input, times) = header("Time", numbers(parse_u32))(input).unwrap();
+        let (input, _) = newline::<_, ErrorTree<&str>>(input).unwrap();
+        let (input, distances) = header("Distance", numbers(parse_u32))(input).unwrap();
+        assert_eq!(
+            (input, times, distances),
+            ("", vec![7, 15, 30], vec![9, 40, 200])
+        );
+        assert!(header("Time", parse_u32)("Distance: 9").is_err());
+    }
+
+    #[test]
+    fn could_parse_blocks() {
+        let input = "1 2\n3\n\n4\n\n5 6";
+        let lines = separated_list1(newline, numbers(parse_u32));
+        let (rest, blocks) = blocks(lines)(input).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            blocks,
+            vec![vec![vec![1, 2], vec![3]], vec![vec![4]], vec![vec![5, 6]]]
+        );
+        let lines = separated_list1(line_ending, numbers(parse_u32));
+        let input = input.replace('\n', "\r\n");
+        let (_, crlf) = super::blocks(lines)(&input).unwrap();
+        assert_eq!(crlf, blocks);
+    }
+
+    #[test]
+    fn could_parse_grid() {
+        let input = "#.#\n.#.\n\nrest";
+        let (rest, lights) = grid(|c| Some(c == '#'))(input).unwrap();
+        assert_eq!(rest, "\n\nrest");
+        assert_eq!((lights.width(), lights.height()), (3, 2));
+        assert!(lights[(1, 1)] && !lights[(1, 0)]);
+        let input = "#.#\r\n.#.\r\n\r\nrest";
+        let (rest, crlf) = grid(|c| Some(c == '#'))(input).unwrap();
+        assert_eq!((rest, crlf), ("\r\n\r\nrest", lights));
+
+        let input = "12\n3x";
+        let err = finish(input, grid(|c| c.to_digit(10))(input)).unwrap_err();
+        assert_eq!(err.message, "expected a grid cell, not 'x'");
+        assert_eq!(err.position.map(|p| (p.line, p.column)), Some((2, 2)));
+    }
+}
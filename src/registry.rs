@@ -0,0 +1,23 @@
+use aoc::{y2022, y2023, Puzzle};
+
+pub fn puzzles() -> Vec<Puzzle> {
+    let mut puzzles = vec![
+        Puzzle::new::<y2023::day01::Day1>(),
+        Puzzle::new::<y2023::day02::Day2>(),
+        Puzzle::new::<y2023::day03::Day3>(),
+        Puzzle::new::<y2023::day04::Day4>(),
+        Puzzle::new::<y2023::day05::Day5>(),
+        Puzzle::new::<y2023::day06::Day6>(),
+        Puzzle::new::<y2023::day07::Day7>(),
+        Puzzle::new::<y2023::day08::Day8>(),
+        Puzzle::new::<y2022::day18::Day18>(),
+    ];
+    puzzles.sort_by_key(|puzzle| (puzzle.year, puzzle.day));
+    puzzles
+}
+
+pub fn find(puzzles: &[Puzzle], year: u16, day: u8) -> Option<&Puzzle> {
+    puzzles
+        .iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
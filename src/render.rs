@@ -0,0 +1,207 @@
+use std::fmt::Write;
+
+use crate::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Color {
+    fn foreground(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::Grey => 90,
+        }
+    }
+
+    fn background(self) -> u8 {
+        self.foreground() + 10
+    }
+}
+
+/// How a cell is drawn. `plain` stands in for the cell's character when colours are off,
+/// so a highlight that only shows in colour can still show up in a text snapshot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub plain: Option<char>,
+}
+
+impl Style {
+    pub const PLAIN: Style = Style {
+        fg: None,
+        bg: None,
+        bold: false,
+        plain: None,
+    };
+
+    pub fn fg(self, color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    pub fn bg(self, color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    pub fn bold(self) -> Self {
+        Self { bold: true, ..self }
+    }
+
+    pub fn plain(self, c: char) -> Self {
+        Self {
+            plain: Some(c),
+            ..self
+        }
+    }
+
+    fn codes(&self) -> Vec<String> {
+        let mut codes = vec![];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        codes.extend(self.fg.map(|color| color.foreground().to_string()));
+        codes.extend(self.bg.map(|color| color.background().to_string()));
+        codes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub c: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn new(c: char) -> Self {
+        Self::styled(c, Style::PLAIN)
+    }
+
+    pub fn styled(c: char, style: Style) -> Self {
+        Self { c, style }
+    }
+}
+
+pub type Canvas = Grid<Cell>;
+
+/// One titled picture, e.g. a single slice of something three dimensional.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub title: String,
+    pub canvas: Canvas,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Colours through ANSI escape codes, for a terminal.
+    Ansi,
+    /// Characters only, for files and snapshot tests.
+    Plain,
+}
+
+/// Draws `canvas` row by row, starting a new escape sequence only where the style changes.
+pub fn render(canvas: &Canvas, mode: Mode) -> String {
+    let mut out = String::new();
+    for row in canvas.rows() {
+        let mut current = Style::PLAIN;
+        for cell in row {
+            match mode {
+                Mode::Plain => out.push(cell.style.plain.unwrap_or(cell.c)),
+                Mode::Ansi => {
+                    if cell.style != current {
+                        if !current.codes().is_empty() {
+                            out.push_str("\x1b[0m");
+                        }
+                        let codes = cell.style.codes();
+                        if !codes.is_empty() {
+                            write!(out, "\x1b[{}m", codes.join(";")).unwrap();
+                        }
+                        current = cell.style;
+                    }
+                    out.push(cell.c);
+                }
+            }
+        }
+        if !current.codes().is_empty() {
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Every frame under its title, with a blank line between frames.
+pub fn render_frames(frames: &[Frame], mode: Mode) -> String {
+    frames
+        .iter()
+        .map(|frame| format!("{}\n{}", frame.title, render(&frame.canvas, mode)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let highlight = Style::PLAIN.fg(Color::Red).bold().plain('X');
+        let cells = vec![
+            Cell::new('.'),
+            Cell::styled('1', highlight),
+            Cell::styled('2', highlight),
+            Cell::new('.'),
+            Cell::styled('*', Style::PLAIN.bg(Color::Yellow)),
+            Cell::new('.'),
+        ];
+        Grid::from_vec(3, cells).unwrap()
+    }
+
+    #[test]
+    fn could_render_plain() {
+        assert_eq!(render(&canvas(), Mode::Plain), ".XX\n.*.\n");
+    }
+
+    #[test]
+    fn could_render_ansi() {
+        assert_eq!(
+            render(&canvas(), Mode::Ansi),
+            ".\x1b[1;31m12\x1b[0m\n.\x1b[43m*\x1b[0m.\n"
+        );
+    }
+
+    #[test]
+    fn could_render_frames() {
+        let frames = [
+            Frame {
+                title: "a".to_string(),
+                canvas: Grid::<char>::parse("12").unwrap().map(|&c| Cell::new(c)),
+            },
+            Frame {
+                title: "b".to_string(),
+                canvas: Grid::<char>::parse("3").unwrap().map(|&c| Cell::new(c)),
+            },
+        ];
+        assert_eq!(render_frames(&frames, Mode::Plain), "a\n12\n\nb\n3\n");
+    }
+}
@@ -0,0 +1,387 @@
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use aoc::{json, solver::PartRun, Answer, Part, Puzzle};
+use clap::ValueEnum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Part 1: <answer>` under a heading per day
+    Text,
+    /// One JSON object per line
+    Json,
+    /// A header row, then one row per part
+    Csv,
+    /// Test Anything Protocol, one test per part
+    Tap,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Failed(String),
+    Panicked(String),
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Failed(_) => "failed",
+            Status::Panicked(_) => "panicked",
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Status::Ok => None,
+            Status::Failed(message) | Status::Panicked(message) => Some(message),
+        }
+    }
+}
+
+/// The result of solving one part, as reported by `aoc run`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+impl Record {
+    pub fn from_runs(puzzle: &Puzzle, runs: Vec<PartRun>) -> Vec<Record> {
+        runs.into_iter()
+            .map(|run| {
+                let (answer, status) = match run.answer {
+                    Ok(answer) => (Some(answer), Status::Ok),
+                    Err(e) => (None, Status::Failed(e.to_string())),
+                };
+                Record {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part: run.part,
+                    answer,
+                    duration: run.elapsed,
+                    status,
+                }
+            })
+            .collect()
+    }
+
+    /// Every one of `parts` failing for the same reason, e.g. a missing input.
+    pub fn failed(puzzle: &Puzzle, parts: &[Part], message: String) -> Vec<Record> {
+        parts
+            .iter()
+            .map(|&part| Record {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::Failed(message.clone()),
+            })
+            .collect()
+    }
+
+    /// Solves one part, turning a panic in the solver into a [`Status::Panicked`] record
+    /// so the other parts still run.
+    pub fn solve(puzzle: &Puzzle, input: &str, part: Part) -> Record {
+        match panic::catch_unwind(AssertUnwindSafe(|| puzzle.run(input, &[part]))) {
+            Ok(runs) => Self::from_runs(puzzle, runs).remove(0),
+            Err(payload) => Record {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                answer: None,
+                duration: Duration::ZERO,
+                status: Status::Panicked(panic_message(payload.as_ref())),
+            },
+        }
+    }
+
+    fn answer(&self) -> &str {
+        self.answer.as_ref().map(Answer::as_str).unwrap_or_default()
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "the solver panicked".to_string()
+    }
+}
+
+pub fn render(format: Format, records: &[Record]) -> String {
+    match format {
+        Format::Text => text(records),
+        Format::Json => json(records),
+        Format::Csv => csv(records),
+        Format::Tap => tap(records),
+    }
+}
+
+fn text(records: &[Record]) -> String {
+    let mut out = String::new();
+    let mut day = None;
+    for record in records {
+        if day != Some((record.year, record.day)) {
+            day = Some((record.year, record.day));
+            writeln!(out, "{} day {}", record.year, record.day).unwrap();
+        }
+        match &record.status {
+            Status::Ok => writeln!(out, "Part {}: {}", record.part, record.answer()).unwrap(),
+            Status::Failed(message) => {
+                writeln!(out, "Part {} failed: {}", record.part, message).unwrap()
+            }
+            Status::Panicked(message) => {
+                writeln!(out, "Part {} panicked: {}", record.part, message).unwrap()
+            }
+        }
+    }
+    out
+}
+
+fn json(records: &[Record]) -> String {
+    let mut out = String::new();
+    for record in records {
+        write!(
+            out,
+            r#"{{"year":{},"day":{},"part":{},"answer":{},"duration_ns":{},"status":"{}""#,
+            record.year,
+            record.day,
+            record.part,
+            record
+                .answer
+                .as_ref()
+                .map_or("null".to_string(), |answer| json::string(answer.as_str())),
+            record.duration.as_nanos(),
+            record.status.name()
+        )
+        .unwrap();
+        if let Some(error) = record.status.error() {
+            write!(out, r#","error":{}"#, json::string(error)).unwrap();
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+fn csv(records: &[Record]) -> String {
+    let mut out = String::from("year,day,part,answer,duration_ns,status,error\n");
+    for record in records {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            csv_field(record.answer()),
+            record.duration.as_nanos(),
+            record.status.name(),
+            csv_field(record.status.error().unwrap_or_default())
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// TAP version 13, with the answer and duration in each test's YAML block.
+fn tap(records: &[Record]) -> String {
+    let mut out = format!("TAP version 13\n1..{}\n", records.len());
+    for (i, record) in records.iter().enumerate() {
+        let ok = match record.status {
+            Status::Ok => "ok",
+            Status::Failed(_) | Status::Panicked(_) => "not ok",
+        };
+        writeln!(
+            out,
+            "{} {} - {} day {} part {}",
+            ok,
+            i + 1,
+            record.year,
+            record.day,
+            record.part
+        )
+        .unwrap();
+        out.push_str("  ---\n");
+        match record.status.error() {
+            Some(error) => writeln!(out, "  message: {}", json::string(error)).unwrap(),
+            None => writeln!(out, "  answer: {}", json::string(record.answer())).unwrap(),
+        }
+        writeln!(out, "  duration_ns: {}", record.duration.as_nanos()).unwrap();
+        out.push_str("  ...\n");
+    }
+    out
+}
+
+/// One row per part, then the totals; details of failures are left to [`render`].
+pub fn summary(records: &[Record], wall: Duration) -> String {
+    let mut out = format!(
+        "{:<4}  {:>3}  {:>4}  {:<16}  {:>10}  status\n",
+        "year", "day", "part", "answer", "time"
+    );
+    for record in records {
+        writeln!(
+            out,
+            "{:<4}  {:>3}  {:>4}  {:<16}  {:>10.2?}  {}",
+            record.year,
+            record.day,
+            record.part.number(),
+            record.answer.as_ref().map_or("-", Answer::as_str),
+            record.duration,
+            record.status.name()
+        )
+        .unwrap();
+    }
+    let count = |name| {
+        records
+            .iter()
+            .filter(|record| record.status.name() == name)
+            .count()
+    };
+    let solving: Duration = records.iter().map(|record| record.duration).sum();
+    writeln!(
+        out,
+        "{} parts: {} ok, {} failed, {} panicked in {:.2?} ({:.2?} solving)",
+        records.len(),
+        count("ok"),
+        count("failed"),
+        count("panicked"),
+        wall,
+        solving
+    )
+    .unwrap();
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                year: 2023,
+                day: 5,
+                part: Part::One,
+                answer: Some(Answer::from(35)),
+                duration: Duration::from_micros(12),
+                status: Status::Ok,
+            },
+            Record {
+                year: 2023,
+                day: 5,
+                part: Part::Two,
+                answer: None,
+                duration: Duration::from_nanos(7),
+                status: Status::Failed("expected \"x\",\nthen y".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn could_isolate_panics() {
+        struct Panics;
+
+        impl aoc::Solver for Panics {
+            const YEAR: u16 = 2023;
+            const DAY: u8 = 0;
+
+            type Input<'a> = &'a str;
+
+            fn parse(input: &str) -> aoc::Result<Self::Input<'_>> {
+                Ok(input)
+            }
+
+            fn part_one(input: &Self::Input<'_>) -> aoc::Result<Answer> {
+                Ok(input.len().into())
+            }
+
+            fn part_two(input: &Self::Input<'_>) -> aoc::Result<Answer> {
+                panic!("index out of bounds in {}", input)
+            }
+        }
+
+        let puzzle = Puzzle::new::<Panics>();
+        let record = Record::solve(&puzzle, "abc", Part::Two);
+        assert_eq!(
+            record.status,
+            Status::Panicked("index out of bounds in abc".to_string())
+        );
+        let record = Record::solve(&puzzle, "abc", Part::One);
+        assert_eq!(record.answer, Some(Answer::from(3)));
+    }
+
+    #[test]
+    fn could_summarize_records() {
+        let mut records = records();
+        records[0].duration = Duration::from_millis(2);
+        let summary = summary(&records, Duration::from_millis(3));
+        assert_eq!(
+            summary,
+            "year  day  part  answer                  time  status\n\
+             2023    5     1  35                    2.00ms  ok\n\
+             2023    5     2  -                     7.00ns  failed\n\
+             2 parts: 1 ok, 1 failed, 0 panicked in 3.00ms (2.00ms solving)\n"
+        );
+    }
+
+    #[test]
+    fn could_render_text() {
+        assert_eq!(
+            render(Format::Text, &records()),
+            "2023 day 5\nPart 1: 35\nPart 2 failed: expected \"x\",\nthen y\n"
+        );
+    }
+
+    #[test]
+    fn could_render_json() {
+        assert_eq!(
+            render(Format::Json, &records()),
+            concat!(
+                r#"{"year":2023,"day":5,"part":1,"answer":"35","duration_ns":12000,"status":"ok"}"#,
+                "\n",
+                r#"{"year":2023,"day":5,"part":2,"answer":null,"duration_ns":7,"status":"failed","error":"expected \"x\",\nthen y"}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn could_render_csv() {
+        assert_eq!(
+            render(Format::Csv, &records()),
+            "year,day,part,answer,duration_ns,status,error\n\
+             2023,5,1,35,12000,ok,\n\
+             2023,5,2,,7,failed,\"expected \"\"x\"\",\nthen y\"\n"
+        );
+    }
+
+    #[test]
+    fn could_render_tap() {
+        assert_eq!(
+            render(Format::Tap, &records()),
+            "TAP version 13\n1..2\n\
+             ok 1 - 2023 day 5 part 1\n  ---\n  answer: \"35\"\n  duration_ns: 12000\n  ...\n\
+             not ok 2 - 2023 day 5 part 2\n  ---\n  message: \"expected \\\"x\\\",\\nthen y\"\n  duration_ns: 7\n  ...\n"
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+const MODULE: &str = r#"use crate::{error::finish, Answer, Error, Result, Solver};
+
+pub mod parser;
+
+pub struct Day{day};
+
+impl Solver for Day{day} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part_1(input)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<&str>> {
+    finish(input, parser::parse(input))
+}
+
+pub fn solve_part_1(_lines: &[&str]) -> Result<usize> {
+    Err(Error::new("part 1 is not solved yet"))
+}
+
+pub fn solve_part_2(_lines: &[&str]) -> Result<usize> {
+    Err(Error::new("part 2 is not solved yet"))
+}
+
+#[cfg(test)]
+mod tests {
+    crate::sample_tests!(super::Day{day} {
+        could_solve_part_1: One { file "sample.txt" => "TODO" }
+        could_solve_part_2: Two { file "sample.txt" => "TODO" }
+    });
+}
+"#;
+
+const PARSER: &str = r#"use crate::error::IResult;
+use nom::{
+    character::complete::{line_ending, not_line_ending},
+    multi::separated_list1,
+    Parser,
+};
+use nom_supreme::ParserExt;
+
+pub fn parse(input: &str) -> IResult<'_, Vec<&str>> {
+    separated_list1(line_ending, not_line_ending)
+        .context("line")
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_parse_lines() {
+        assert_eq!(parse("a\nb").unwrap().1, vec!["a", "b"]);
+    }
+}
+"#;
+
+const MAIN: &str = r#"use aoc::y{year}::day{dd}::{self, Day{day}};
+
+fn main() -> anyhow::Result<()> {
+    let input = aoc::input::from_args::<Day{day}>()?;
+    let lines = day{dd}::parse(&input)?;
+    println!("Part 1: {}", day{dd}::solve_part_1(&lines)?);
+    println!("Part 2: {}", day{dd}::solve_part_2(&lines)?);
+    Ok(())
+}
+"#;
+
+/// Everything `aoc new` writes for one day.
+pub struct Scaffold {
+    root: PathBuf,
+    year: u16,
+    day: u8,
+}
+
+impl Scaffold {
+    /// `root` is the crate directory, holding `src/lib.rs` and `src/registry.rs`.
+    pub fn new(root: impl Into<PathBuf>, year: u16, day: u8) -> Self {
+        Self {
+            root: root.into(),
+            year,
+            day,
+        }
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("{year}", &self.year.to_string())
+            .replace("{day}", &self.day.to_string())
+            .replace("{dd}", &format!("{:02}", self.day))
+    }
+
+    fn year_module(&self) -> String {
+        format!("y{}", self.year)
+    }
+
+    fn day_module(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    fn dir(&self) -> PathBuf {
+        self.root
+            .join("src")
+            .join(self.year_module())
+            .join(self.day_module())
+    }
+
+    /// `y2023_day05`, so the same day of two years gets two binaries.
+    fn bin(&self) -> PathBuf {
+        self.root
+            .join("src/bin")
+            .join(format!("{}_{}.rs", self.year_module(), self.day_module()))
+    }
+
+    fn register(&self, registry: &str) -> Result<String> {
+        let entry = format!(
+            "        Puzzle::new::<{}::{}::Day{}>(),\n",
+            self.year_module(),
+            self.day_module(),
+            self.day
+        );
+        if registry.contains(&entry) {
+            bail!("{} day {} is already in the registry", self.year, self.day);
+        }
+        let Some(import) = registry.find("use aoc::{") else {
+            bail!("could not find `use aoc::{{...}}` in the registry");
+        };
+        let names_start = import + "use aoc::{".len();
+        let Some(names_end) = registry[names_start..].find("};").map(|i| names_start + i) else {
+            bail!("could not find the end of `use aoc::{{...}}` in the registry");
+        };
+        let mut names: Vec<_> = registry[names_start..names_end].split(", ").collect();
+        let year = self.year_module();
+        if !names.contains(&year.as_str()) {
+            names.push(&year);
+            // Modules before types, as rustfmt orders them.
+            names.sort_by_key(|name| (name.starts_with(char::is_uppercase), *name));
+        }
+        let Some(list_end) = registry[names_end..].find("    ];").map(|i| names_end + i) else {
+            bail!("could not find the end of the puzzle list in the registry");
+        };
+        Ok(format!(
+            "{}{}{}{}{}",
+            &registry[..names_start],
+            names.join(", "),
+            &registry[names_end..list_end],
+            entry,
+            &registry[list_end..]
+        ))
+    }
+
+    /// Writes the day's files and registers it, returning what was written.
+    /// Fails without touching anything if the day already exists.
+    pub fn create(&self) -> Result<Vec<PathBuf>> {
+        let (dir, bin) = (self.dir(), self.bin());
+        for path in [&dir, &bin] {
+            if path.exists() {
+                bail!("{} already exists", path.display());
+            }
+        }
+        let registry_path = self.root.join("src/registry.rs");
+        let registry = self.register(&read(&registry_path)?)?;
+
+        let year_path = self
+            .root
+            .join("src")
+            .join(self.year_module())
+            .join("mod.rs");
+        let mut files = vec![];
+        if year_path.exists() {
+            let modules = read(&year_path)?;
+            files.push((year_path, declare(&modules, &self.day_module())));
+        } else {
+            let lib_path = self.root.join("src/lib.rs");
+            let lib = read(&lib_path)?;
+            files.push((lib_path, declare(&lib, &self.year_module())));
+            files.push((year_path, declare("", &self.day_module())));
+        }
+        files.extend([
+            (dir.join("mod.rs"), self.fill(MODULE)),
+            (dir.join("parser.rs"), self.fill(PARSER)),
+            (dir.join("sample.txt"), String::new()),
+            (bin, self.fill(MAIN)),
+            (registry_path, registry),
+        ]);
+        fs::create_dir_all(&dir).with_context(|| format!("could not create {}", dir.display()))?;
+        for (path, contents) in &files {
+            write(path, contents)?;
+        }
+        Ok(files.into_iter().map(|(path, _)| path).collect())
+    }
+}
+
+/// Adds `pub mod <module>;` to the sorted `pub mod` lines of `source`.
+fn declare(source: &str, module: &str) -> String {
+    let declaration = format!("pub mod {};", module);
+    let mut lines: Vec<&str> = source.lines().collect();
+    let at = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > declaration.as_str())
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod "))
+                .map(|i| i + 1)
+        })
+        .unwrap_or(0);
+    lines.insert(at, &declaration);
+    lines.join("\n") + "\n"
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("could not read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("could not write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = include_str!("registry.rs");
+    const LIB: &str = include_str!("lib.rs");
+    const Y2023: &str = include_str!("y2023/mod.rs");
+
+    fn root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::create_dir_all(root.join("src/y2023/day01")).unwrap();
+        fs::write(root.join("src/bin/y2023_day01.rs"), "").unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/y2023/mod.rs"), Y2023).unwrap();
+        root
+    }
+
+    #[test]
+    fn could_create_day() {
+        let root = root("create");
+        let written = Scaffold::new(&root, 2023, 9).create().unwrap();
+        assert_eq!(written.len(), 6);
+        let module = fs::read_to_string(root.join("src/y2023/day09/mod.rs")).unwrap();
+        assert!(module.contains("pub struct Day9;"));
+        assert!(module.contains("const YEAR: u16 = 2023;"));
+        assert!(module.contains("pub fn solve_part_1("));
+        let main = fs::read_to_string(root.join("src/bin/y2023_day09.rs")).unwrap();
+        assert!(main.starts_with("use aoc::y2023::day09::{self, Day9};"));
+        assert!(main.contains("println!(\"Part 1: {}\", day09::solve_part_1(&lines)?);"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2023/day09/sample.txt")).unwrap(),
+            ""
+        );
+        let modules = fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap();
+        assert!(modules.ends_with("pub mod day08;\npub mod day09;\n"));
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains("        Puzzle::new::<y2023::day09::Day9>(),\n    ];"));
+        assert_eq!(fs::read_to_string(root.join("src/lib.rs")).unwrap(), LIB);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn could_create_year() {
+        let root = root("year");
+        let written = Scaffold::new(&root, 2021, 12).create().unwrap();
+        assert_eq!(written.len(), 7);
+        assert!(root.join("src/bin/y2021_day12.rs").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2021/mod.rs")).unwrap(),
+            "pub mod day12;\n"
+        );
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub mod voxel;\npub mod y2021;\npub mod y2022;\n"));
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.starts_with("use aoc::{y2021, y2022, y2023, Puzzle};\n"));
+        assert!(registry.contains("        Puzzle::new::<y2021::day12::Day12>(),\n    ];"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn could_refuse_existing_day() {
+        let root = root("existing");
+        let err = Scaffold::new(&root, 2023, 1).create().unwrap_err();
+        assert!(err.to_string().ends_with("day01 already exists"));
+        fs::remove_dir_all(root.join("src/y2023/day01")).unwrap();
+        let err = Scaffold::new(&root, 2023, 1).create().unwrap_err();
+        assert!(err.to_string().ends_with("y2023_day01.rs already exists"));
+        fs::remove_file(root.join("src/bin/y2023_day01.rs")).unwrap();
+        let err = Scaffold::new(&root, 2023, 1).create().unwrap_err();
+        assert_eq!(err.to_string(), "2023 day 1 is already in the registry");
+        assert!(!root.join("src/y2023/day01").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src/registry.rs")).unwrap(),
+            REGISTRY
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,378 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::{self, Options, Stage, Timings},
+    error::Result,
+    input::normalize,
+    render::Frame,
+    trace::{self, Event},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Every day returns a different integer type, so answers are kept as their printed form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl Answer {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(i32, i64, u32, u64, usize, String, &str);
+
+/// A single puzzle: parse the input once, then answer both parts from the parsed value.
+pub trait Solver {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer>;
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer>;
+
+    /// Pictures of the parsed input for `aoc run --visualize`; days without any draw nothing.
+    fn visualize(_input: &Self::Input<'_>) -> Result<Vec<Frame>> {
+        Ok(vec![])
+    }
+}
+
+type Answers = Vec<(Part, Answer)>;
+
+fn solve_parts<S: Solver>(input: &str, parts: &[Part]) -> Result<Answers> {
+    let input = &normalize(input);
+    let parsed = S::parse(input).map_err(|e| e.in_day(S::YEAR, S::DAY))?;
+    let mut answers = Vec::with_capacity(parts.len());
+    for &part in parts {
+        let answer = match part {
+            Part::One => S::part_one(&parsed),
+            Part::Two => S::part_two(&parsed),
+        };
+        let answer = answer.map_err(|e| e.in_day(S::YEAR, S::DAY).in_part(part))?;
+        answers.push((part, answer));
+    }
+    Ok(answers)
+}
+
+fn visualize_input<S: Solver>(input: &str) -> Result<Vec<Frame>> {
+    let input = &normalize(input);
+    let parsed = S::parse(input).map_err(|e| e.in_day(S::YEAR, S::DAY))?;
+    S::visualize(&parsed).map_err(|e| e.in_day(S::YEAR, S::DAY))
+}
+
+/// What each stage of a [`Puzzle::explain`] emitted, parsing first.
+pub type Explanation = Vec<(Stage, Vec<Event>)>;
+
+/// Solves `parts` with tracing on. Failing parts still explain what they got to;
+/// their errors are left to [`Puzzle::run`] to report.
+fn explain_parts<S: Solver>(input: &str, parts: &[Part]) -> Result<Explanation> {
+    let input = &normalize(input);
+    let (parsed, events) = trace::collect(|| S::parse(input));
+    let parsed = parsed.map_err(|e| e.in_day(S::YEAR, S::DAY))?;
+    let mut stages = vec![(Stage::Parse, events)];
+    for &part in parts {
+        let (_, events) = trace::collect(|| match part {
+            Part::One => S::part_one(&parsed),
+            Part::Two => S::part_two(&parsed),
+        });
+        stages.push((Stage::Part(part), events));
+    }
+    Ok(stages)
+}
+
+/// One part of a [`Puzzle::run`] and how long solving it took, parsing not included.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// Like [`solve_parts`], but a failing part does not hide the others.
+/// A failed parse fails every part, with the time it took to fail.
+fn run_parts<S: Solver>(input: &str, parts: &[Part]) -> Vec<PartRun> {
+    let input = &normalize(input);
+    let start = Instant::now();
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let elapsed = start.elapsed();
+            let e = e.in_day(S::YEAR, S::DAY);
+            return parts
+                .iter()
+                .map(|&part| PartRun {
+                    part,
+                    answer: Err(e.clone()),
+                    elapsed,
+                })
+                .collect();
+        }
+    };
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part_one(&parsed),
+                Part::Two => S::part_two(&parsed),
+            };
+            PartRun {
+                part,
+                answer: answer.map_err(|e| e.in_day(S::YEAR, S::DAY).in_part(part)),
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+/// A type-erased [`Solver`], as kept in the runner's registry.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str, &[Part]) -> Result<Answers>,
+    run: fn(&str, &[Part]) -> Vec<PartRun>,
+    visualize: fn(&str) -> Result<Vec<Frame>>,
+    explain: fn(&str, &[Part]) -> Result<Explanation>,
+    bench: fn(&str, &[Part], Options) -> Result<Timings>,
+}
+
+impl Puzzle {
+    pub fn new<S: Solver>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve_parts::<S>,
+            run: run_parts::<S>,
+            visualize: visualize_input::<S>,
+            explain: explain_parts::<S>,
+            bench: bench::bench_parts::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Answers> {
+        (self.solve)(input, parts)
+    }
+
+    /// Solves each of `parts` once, timing it and keeping going past failures.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Vec<PartRun> {
+        (self.run)(input, parts)
+    }
+
+    pub fn visualize(&self, input: &str) -> Result<Vec<Frame>> {
+        (self.visualize)(input)
+    }
+
+    /// The [`trace!`](crate::trace!) events of parsing and of each of `parts`.
+    pub fn explain(&self, input: &str, parts: &[Part]) -> Result<Explanation> {
+        (self.explain)(input, parts)
+    }
+
+    /// Times parsing and each of `parts` separately.
+    pub fn bench(&self, input: &str, parts: &[Part], options: Options) -> Result<Timings> {
+        (self.bench)(input, parts, options)
+    }
+}
+
+/// Used by [`sample_tests!`](crate::sample_tests): solves one part of `input` and checks the answer.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_sample<S: Solver>(input: &str, part: Part, expected: impl Into<Answer>) {
+    match Puzzle::new::<S>().solve(input, &[part]) {
+        Ok(answers) => assert_eq!(
+            answers[0].1,
+            expected.into(),
+            "part {} of {:?}",
+            part,
+            input
+        ),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    struct Sum;
+
+    impl Solver for Sum {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| Error::new(format!("{:?} is not a number", line)))
+                })
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+            for (i, value) in input.iter().enumerate() {
+                crate::trace!(line: i + 1, "add", value = value);
+            }
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+            Ok(input.iter().max().copied().unwrap_or_default().into())
+        }
+    }
+
+    #[test]
+    fn could_solve_puzzle() {
+        let puzzle = Puzzle::new::<Sum>();
+        assert_eq!((puzzle.year, puzzle.day), (2023, 0));
+        let answers = puzzle.solve("1\n5\n3", &Part::ALL).unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                (Part::One, Answer::from(9u32)),
+                (Part::Two, Answer::from(5u32))
+            ]
+        );
+        let answers = puzzle.solve("1\n5\n3", &[Part::Two]).unwrap();
+        assert_eq!(answers, vec![(Part::Two, Answer::from(5u32))]);
+    }
+
+    #[test]
+    fn could_bench_puzzle() {
+        let puzzle = Puzzle::new::<Sum>();
+        let options = Options {
+            warmup: 1,
+            iterations: 3,
+        };
+        let timings = puzzle.bench("1\n5\n3", &[Part::Two], options).unwrap();
+        let stages: Vec<_> = timings.iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(
+            stages,
+            vec![bench::Stage::Parse, bench::Stage::Part(Part::Two)]
+        );
+        assert!(timings.iter().all(|(_, stats)| stats.iterations == 3));
+        assert!(puzzle.bench("x", &Part::ALL, options).is_err());
+    }
+
+    #[test]
+    fn could_run_puzzle() {
+        let puzzle = Puzzle::new::<Sum>();
+        let runs = puzzle.run("1\n5\n3", &Part::ALL);
+        let answers: Vec<_> = runs
+            .iter()
+            .map(|run| (run.part, run.answer.clone().unwrap()))
+            .collect();
+        assert_eq!(answers, puzzle.solve("1\n5\n3", &Part::ALL).unwrap());
+        let runs = puzzle.run("1\nx", &Part::ALL);
+        assert_eq!(runs.len(), 2);
+        for run in runs {
+            assert_eq!(run.answer.unwrap_err().message, "\"x\" is not a number");
+        }
+    }
+
+    #[test]
+    fn could_explain_puzzle() {
+        let puzzle = Puzzle::new::<Sum>();
+        let stages = puzzle.explain("1\n5", &Part::ALL).unwrap();
+        let stages: Vec<_> = stages
+            .iter()
+            .map(|(stage, events)| {
+                let events: Vec<_> = events.iter().map(|event| event.to_string()).collect();
+                (*stage, events)
+            })
+            .collect();
+        assert_eq!(
+            stages,
+            vec![
+                (Stage::Parse, vec![]),
+                (
+                    Stage::Part(Part::One),
+                    vec![
+                        "line 1: add value=1".to_string(),
+                        "line 2: add value=5".to_string()
+                    ]
+                ),
+                (Stage::Part(Part::Two), vec![]),
+            ]
+        );
+        assert!(puzzle.explain("x", &Part::ALL).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1")]
+    fn could_fail_wrong_sample() {
+        assert_sample::<Sum>("1\n2", Part::One, 4u32);
+    }
+
+    #[test]
+    fn could_solve_windows_input() {
+        let puzzle = Puzzle::new::<Sum>();
+        let answers = puzzle
+            .solve("\u{feff}1\r\n5\r\n3\r\n\r\n", &[Part::One])
+            .unwrap();
+        assert_eq!(answers, vec![(Part::One, Answer::from(9u32))]);
+    }
+
+    #[test]
+    fn could_report_parse_error() {
+        let puzzle = Puzzle::new::<Sum>();
+        let err = puzzle.solve("1\nx", &Part::ALL).unwrap_err();
+        assert_eq!(err.to_string(), "2023 day 0 input: \"x\" is not a number");
+    }
+}
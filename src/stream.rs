@@ -0,0 +1,80 @@
+use std::io::BufRead;
+
+use crate::error::{finish, Error, IResult, Result};
+
+/// Calls `line` with every non-blank line of `reader` and its 1-based number, reusing a
+/// single buffer so memory stays bounded by the longest line. Line endings and a leading
+/// byte order mark are stripped, as [`crate::input::normalize`] would.
+pub fn for_each_line<R: BufRead>(
+    mut reader: R,
+    mut line: impl FnMut(usize, &str) -> Result<()>,
+) -> Result<()> {
+    let mut buffer = String::new();
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        let read = reader
+            .read_line(&mut buffer)
+            .map_err(|e| Error::new(format!("could not read input: {}", e)))?;
+        if read == 0 {
+            return Ok(());
+        }
+        number += 1;
+        let mut text = buffer.trim_end_matches(['\r', '\n']);
+        if number == 1 {
+            text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        }
+        if !text.trim().is_empty() {
+            line(number, text)?;
+        }
+    }
+}
+
+/// Like [`finish`] for a single streamed line, reporting errors at line `number`.
+pub fn parse_line<'a, T>(number: usize, line: &'a str, result: IResult<'a, T>) -> Result<T> {
+    finish(line, result).map_err(|mut e| {
+        if let Some(position) = &mut e.position {
+            position.line = number;
+        }
+        e
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Position;
+    use nom::{character::complete::u32 as parse_u32, sequence::preceded};
+    use nom_supreme::tag::complete::tag;
+    use std::io::Cursor;
+
+    fn parse_id(input: &str) -> IResult<'_, u32> {
+        preceded(tag("id "), parse_u32)(input)
+    }
+
+    #[test]
+    fn could_stream_lines() {
+        let reader = Cursor::new("\u{feff}id 1\r\n\r\nid 2\nid 3\n\n");
+        let mut ids = vec![];
+        for_each_line(reader, |number, line| {
+            ids.push((number, parse_line(number, line, parse_id(line))?));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(ids, vec![(1, 1), (3, 2), (4, 3)]);
+    }
+
+    #[test]
+    fn could_report_streamed_line() {
+        let reader = Cursor::new("id 1\nid 2\nid x\nid 4");
+        let mut seen = 0;
+        let err = for_each_line(reader, |number, line| {
+            seen += 1;
+            parse_line(number, line, parse_id(line)).map(|_| ())
+        })
+        .unwrap_err();
+        assert_eq!(seen, 3);
+        assert_eq!(err.position, Some(Position { line: 3, column: 4 }));
+        assert_eq!(err.source_line.as_deref(), Some("id x"));
+    }
+}
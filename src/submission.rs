@@ -0,0 +1,512 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{Answer, Part};
+
+/// Every answer sent to the site and how it was judged, kept under the inputs directory.
+/// One per line: `<year> <day> <part> <answer> <outcome> [retry at, in unix seconds]`.
+pub const SUBMISSIONS_FILE: &str = "submissions.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Incorrect,
+    /// Sent too soon after the last one, so it was not judged at all.
+    Wait,
+    /// The site is not asking for this part: it was solved before, possibly from a browser,
+    /// or part one is still open. Either way nothing was judged, so it is never recorded.
+    WrongLevel,
+}
+
+impl Outcome {
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+
+    /// Whether the verdict says nothing lasting about the answer or the part.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Outcome::WrongLevel)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::Wait => "wait",
+            Outcome::WrongLevel => "wrong-level",
+        })
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "incorrect" => Outcome::Incorrect,
+            "wait" => Outcome::Wait,
+            "wrong-level" => Outcome::WrongLevel,
+            _ => bail!("unknown outcome {:?}", s),
+        })
+    }
+}
+
+/// What the site said about a submission, and how long it wants us to wait before the next one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    pub outcome: Outcome,
+    pub wait: Option<Duration>,
+}
+
+/// Reads the verdict out of the page the site answers a submission with.
+pub fn parse_response(html: &str) -> Result<Verdict> {
+    let text = article_text(html);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if text.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        Outcome::Incorrect
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::Wait
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        bail!("could not understand the response: {:?}", text);
+    };
+    let wait = left_to_wait(&text).or_else(|| wait_before_retry(&text));
+    Ok(Verdict { outcome, wait })
+}
+
+/// The text of the page's `<article>`, tags dropped and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let body = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `You have 1m 3s left to wait.`
+fn left_to_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for amount in text[start..end].split_whitespace() {
+        let unit = match amount.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        seconds += amount[..amount.len() - 1].parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// `please wait one minute before trying again`, `please wait 5 minutes before trying again`
+fn wait_before_retry(text: &str) -> Option<Duration> {
+    let text = text.to_lowercase();
+    let start = text.find("please wait ")? + "please wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let amount = match words.next()? {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    let unit = match words.next()?.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 3600,
+        _ => return None,
+    };
+    Some(Duration::from_secs(amount * unit))
+}
+
+/// Checks that `answer` can be sent and recorded: the history is split on whitespace.
+pub fn check_answer(answer: &Answer) -> Result<()> {
+    if answer.as_str().is_empty() {
+        bail!("the answer is empty");
+    }
+    if answer.as_str().contains(char::is_whitespace) {
+        bail!("the answer {:?} contains whitespace", answer.as_str());
+    }
+    Ok(())
+}
+
+/// Time since the unix epoch.
+pub fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub outcome: Outcome,
+    /// Unix seconds before which the site will not judge another answer for this day.
+    pub retry_at: Option<u64>,
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.year, self.day, self.part, self.answer, self.outcome
+        )?;
+        if let Some(retry_at) = self.retry_at {
+            write!(f, " {}", retry_at)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_line(line: &str) -> Result<Submission> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let (year, day, part, answer, outcome, retry_at) = match fields.as_slice() {
+        [year, day, part, answer, outcome] => (year, day, part, answer, outcome, None),
+        [year, day, part, answer, outcome, retry_at] => {
+            (year, day, part, answer, outcome, Some(retry_at))
+        }
+        _ => bail!(
+            "expected `<year> <day> <part> <answer> <outcome> [retry at]`, got {:?}",
+            line
+        ),
+    };
+    let part = part
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .with_context(|| format!("part must be 1 or 2, got {:?}", part))?;
+    Ok(Submission {
+        year: year
+            .parse()
+            .with_context(|| format!("bad year {:?}", year))?,
+        day: day.parse().with_context(|| format!("bad day {:?}", day))?,
+        part,
+        answer: Answer::from(*answer),
+        outcome: outcome.parse()?,
+        retry_at: retry_at
+            .map(|at| {
+                at.parse()
+                    .with_context(|| format!("bad retry time {:?}", at))
+            })
+            .transpose()?,
+    })
+}
+
+/// Why an answer is not worth sending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved(Answer),
+    Rejected(Outcome),
+    AtLeast(Answer),
+    AtMost(Answer),
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved(answer) => write!(f, "this part was already solved with {}", answer),
+            Refusal::Rejected(outcome) => write!(f, "it was already rejected as {}", outcome),
+            Refusal::AtLeast(high) => write!(f, "{} was already too high", high),
+            Refusal::AtMost(low) => write!(f, "{} was already too low", low),
+            Refusal::Cooldown(left) => write!(
+                f,
+                "the site wants {}s more before the next answer",
+                left.as_secs()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut submissions = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            submissions.push(
+                parse_line(line).with_context(|| format!("{} line {}", SUBMISSIONS_FILE, i + 1))?,
+            );
+        }
+        Ok(Self { submissions })
+    }
+
+    /// A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("could not read {}", path.display())),
+        }
+    }
+
+    /// Records `submission` here and appends it to the file at `path`.
+    pub fn record(&mut self, path: &Path, submission: Submission) -> Result<()> {
+        check_answer(&submission.answer)?;
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", submission))
+            .with_context(|| format!("could not write {}", path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Checks `answer` against everything learned so far, `now` being unix seconds.
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        now: u64,
+    ) -> Option<Refusal> {
+        let same_day = || {
+            self.submissions
+                .iter()
+                .filter(move |s| (s.year, s.day) == (year, day))
+        };
+        let same_part = || same_day().filter(move |s| s.part == part);
+        if let Some(solved) = same_part().find(|s| s.outcome == Outcome::Correct) {
+            return Some(Refusal::Solved(solved.answer.clone()));
+        }
+        if let Some(rejected) = same_part().find(|s| s.outcome.is_wrong() && &s.answer == answer) {
+            return Some(Refusal::Rejected(rejected.outcome));
+        }
+        if let Ok(value) = answer.as_str().parse::<i128>() {
+            let bound = |outcome| {
+                same_part()
+                    .filter(move |s| s.outcome == outcome)
+                    .filter_map(|s| Some((s.answer.as_str().parse::<i128>().ok()?, &s.answer)))
+            };
+            if let Some((_, high)) = bound(Outcome::TooHigh)
+                .filter(|(high, _)| value >= *high)
+                .min_by_key(|(high, _)| *high)
+            {
+                return Some(Refusal::AtLeast(high.clone()));
+            }
+            if let Some((_, low)) = bound(Outcome::TooLow)
+                .filter(|(low, _)| value <= *low)
+                .max_by_key(|(low, _)| *low)
+            {
+                return Some(Refusal::AtMost(low.clone()));
+            }
+        }
+        same_day()
+            .filter_map(|s| s.retry_at)
+            .max()
+            .filter(|&retry_at| retry_at > now)
+            .map(|retry_at| Refusal::Cooldown(Duration::from_secs(retry_at - now)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOO_HIGH: &str = r#"<main>
+<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2023/about">about page</a>, or you can ask for hints on the <a href="https://www.reddit.com/r/adventofcode/" target="_blank">subreddit</a>.  Please wait one minute before trying again. <a href="/2023/day/5">[Return to Day 5]</a></p></article>
+</main>"#;
+
+    fn verdict(outcome: Outcome, wait: Option<u64>) -> Verdict {
+        Verdict {
+            outcome,
+            wait: wait.map(Duration::from_secs),
+        }
+    }
+
+    #[test]
+    fn could_parse_responses() {
+        assert_eq!(
+            parse_response(TOO_HIGH).unwrap(),
+            verdict(Outcome::TooHigh, Some(60))
+        );
+        let correct = "<article><p>That's the right answer!  You are one gold star closer. <a href=\"/2023/day/5#part2\">[Continue to Part Two]</a></p></article>";
+        assert_eq!(
+            parse_response(correct).unwrap(),
+            verdict(Outcome::Correct, None)
+        );
+        let low = "<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>";
+        assert_eq!(
+            parse_response(low).unwrap(),
+            verdict(Outcome::TooLow, Some(60))
+        );
+        let wrong = "<article><p>That's not the right answer.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.</p></article>";
+        assert_eq!(
+            parse_response(wrong).unwrap(),
+            verdict(Outcome::Incorrect, Some(300))
+        );
+        let wait = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 34s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>";
+        assert_eq!(
+            parse_response(wait).unwrap(),
+            verdict(Outcome::Wait, Some(94))
+        );
+        let level = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>";
+        assert_eq!(
+            parse_response(level).unwrap(),
+            verdict(Outcome::WrongLevel, None)
+        );
+        assert!(Outcome::WrongLevel.is_transient());
+        assert!(parse_response("<article><p>Teapot</p></article>").is_err());
+    }
+
+    fn submission(part: Part, answer: &str, outcome: Outcome, retry_at: Option<u64>) -> Submission {
+        Submission {
+            year: 2023,
+            day: 5,
+            part,
+            answer: Answer::from(answer),
+            outcome,
+            retry_at,
+        }
+    }
+
+    #[test]
+    fn could_round_trip_history() {
+        let text = "# comment\n2023 5 1 100 too-high 1700000060\n\n2023 5 2 abc correct\n";
+        let history = History::parse(text).unwrap();
+        assert_eq!(
+            history.submissions(),
+            [
+                submission(Part::One, "100", Outcome::TooHigh, Some(1700000060)),
+                submission(Part::Two, "abc", Outcome::Correct, None),
+            ]
+        );
+        assert_eq!(
+            history.submissions()[0].to_string(),
+            "2023 5 1 100 too-high 1700000060"
+        );
+        let err = History::parse("2023 5 1 100 maybe").unwrap_err();
+        assert_eq!(err.to_string(), "submissions.txt line 1");
+    }
+
+    #[test]
+    fn could_refuse_known_wrong_answers() {
+        let history = History {
+            submissions: vec![
+                submission(Part::One, "100", Outcome::TooHigh, Some(1060)),
+                submission(Part::One, "40", Outcome::TooLow, Some(1120)),
+                submission(Part::One, "70", Outcome::Incorrect, None),
+                submission(Part::Two, "9", Outcome::Correct, None),
+            ],
+        };
+        let check =
+            |part, answer: &str, now| history.check(2023, 5, part, &Answer::from(answer), now);
+        assert_eq!(
+            check(Part::One, "150", 2000),
+            Some(Refusal::AtLeast(Answer::from("100")))
+        );
+        assert_eq!(
+            check(Part::One, "40", 2000),
+            Some(Refusal::Rejected(Outcome::TooLow))
+        );
+        assert_eq!(
+            check(Part::One, "12", 2000),
+            Some(Refusal::AtMost(Answer::from("40")))
+        );
+        assert_eq!(
+            check(Part::One, "70", 2000),
+            Some(Refusal::Rejected(Outcome::Incorrect))
+        );
+        assert_eq!(
+            check(Part::One, "71", 1100),
+            Some(Refusal::Cooldown(Duration::from_secs(20)))
+        );
+        assert_eq!(check(Part::One, "71", 2000), None);
+        assert_eq!(check(Part::One, "x", 2000), None);
+        assert_eq!(
+            check(Part::Two, "10", 2000),
+            Some(Refusal::Solved(Answer::from("9")))
+        );
+        assert_eq!(
+            history.check(2023, 6, Part::One, &Answer::from("150"), 0),
+            None
+        );
+    }
+
+    #[test]
+    fn could_reject_answers_with_whitespace() {
+        assert!(check_answer(&Answer::from("42")).is_ok());
+        assert!(check_answer(&Answer::from("AB CD")).is_err());
+        assert!(check_answer(&Answer::from("")).is_err());
+        let path =
+            std::env::temp_dir().join(format!("aoc-submissions-spaces-{}.txt", std::process::id()));
+        let mut history = History::default();
+        assert!(history
+            .record(&path, submission(Part::One, "1 2", Outcome::TooLow, None))
+            .is_err());
+        assert!(history.submissions().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn could_record_submissions() {
+        let path = std::env::temp_dir().join(format!("aoc-submissions-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut history = History::load(&path).unwrap();
+        let first = submission(Part::One, "100", Outcome::TooHigh, Some(1060));
+        history.record(&path, first.clone()).unwrap();
+        history
+            .record(&path, submission(Part::One, "50", Outcome::Correct, None))
+            .unwrap();
+        assert_eq!(
+            History::load(&path).unwrap().submissions(),
+            history.submissions()
+        );
+        assert_eq!(history.submissions()[0], first);
+        fs::remove_file(path).unwrap();
+    }
+}
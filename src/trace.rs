@@ -0,0 +1,231 @@
+use std::{
+    cell::RefCell,
+    fmt::{Display, Write},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::json;
+
+/// Threads currently inside [`collect`]. While it is zero, [`emit`] returns after a single
+/// load and never builds its event, so solvers can explain themselves at no cost.
+static COLLECTING: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+/// One step a solver took, for `aoc run --explain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// 1-based line of the input the step is about, if it is about a single line.
+    pub line: Option<usize>,
+    pub kind: &'static str,
+    pub fields: Vec<(String, String)>,
+}
+
+impl Event {
+    pub fn new(kind: &'static str) -> Self {
+        Self {
+            line: None,
+            kind,
+            fields: vec![],
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn field(mut self, key: impl Into<String>, value: impl Display) -> Self {
+        self.fields.push((key.into(), value.to_string()));
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The event as a JSON object, with `context` (already JSON) put in front of its own keys.
+    pub fn to_json(&self, context: &str) -> String {
+        let mut out = format!("{{{}", context);
+        if !context.is_empty() {
+            out.push(',');
+        }
+        match self.line {
+            Some(line) => write!(out, r#""line":{},"#, line).unwrap(),
+            None => out.push_str(r#""line":null,"#),
+        }
+        write!(out, r#""kind":{},"fields":{{"#, json::string(self.kind)).unwrap();
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, "{}:{}", json::string(key), json::string(value)).unwrap();
+        }
+        out.push_str("}}");
+        out
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        f.write_str(self.kind)?;
+        for (key, value) in &self.fields {
+            if value.contains(' ') {
+                write!(f, " {}={:?}", key, value)?;
+            } else {
+                write!(f, " {}={}", key, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether an event emitted on this thread would be kept. Worth checking before a loop that
+/// only exists to emit events.
+#[inline]
+pub fn enabled() -> bool {
+    COLLECTING.load(Ordering::Relaxed) > 0 && EVENTS.with(|events| events.borrow().is_some())
+}
+
+/// Records the event built by `event` if this thread is inside [`collect`]; otherwise
+/// `event` is never called.
+#[inline]
+pub fn emit(event: impl FnOnce() -> Event) {
+    if !enabled() {
+        return;
+    }
+    let event = event();
+    EVENTS.with(|events| {
+        if let Some(events) = events.borrow_mut().as_mut() {
+            events.push(event);
+        }
+    });
+}
+
+/// Runs `f` with tracing on for the current thread, returning what it emitted.
+/// Events emitted by other threads, such as rayon workers, are not collected.
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Vec<Event>) {
+    struct Restore(Option<Vec<Event>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            EVENTS.with(|events| *events.borrow_mut() = previous);
+            COLLECTING.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    COLLECTING.fetch_add(1, Ordering::Relaxed);
+    let _restore = Restore(EVENTS.with(|events| events.replace(Some(vec![]))));
+    let result = f();
+    let events = EVENTS
+        .with(|events| events.borrow_mut().take())
+        .unwrap_or_default();
+    (result, events)
+}
+
+/// Emits an [`Event`] of the given kind, optionally about one input line, with a field per
+/// `key = value`. Nothing is evaluated unless a [`collect`] is running on this thread:
+///
+/// ```ignore
+/// aoc::trace!(line: 3, "digits", first = 1, last = 7);
+/// aoc::trace!("chain", seed = 79, location = 82);
+/// ```
+#[macro_export]
+macro_rules! trace {
+    (line: $line:expr, $kind:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::trace::emit(|| {
+            $crate::trace::Event::new($kind)
+                .at_line($line)
+                $(.field(stringify!($key), &$value))*
+        })
+    };
+    ($kind:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::trace::emit(|| {
+            $crate::trace::Event::new($kind)
+                $(.field(stringify!($key), &$value))*
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_collect_events() {
+        let (sum, events) = collect(|| {
+            let mut sum = 0;
+            for (i, value) in [4, 5].into_iter().enumerate() {
+                crate::trace!(line: i + 1, "add", value = value, total = sum + value);
+                sum += value;
+            }
+            crate::trace!("done", sum = sum);
+            sum
+        });
+        assert_eq!(sum, 9);
+        let lines: Vec<_> = events.iter().map(|event| event.to_string()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "line 1: add value=4 total=4",
+                "line 2: add value=5 total=9",
+                "done sum=9"
+            ]
+        );
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn could_skip_building_events_when_not_collecting() {
+        fn costly() -> u64 {
+            panic!("evaluated a field nobody collects")
+        }
+        emit(|| panic!("built an event nobody collects"));
+        crate::trace!("unused", value = costly());
+    }
+
+    #[test]
+    fn could_nest_collections() {
+        let (inner, outer) = collect(|| {
+            crate::trace!("outer");
+            let (_, inner) = collect(|| crate::trace!("inner"));
+            crate::trace!("outer");
+            inner
+        });
+        assert_eq!(inner, vec![Event::new("inner")]);
+        assert_eq!(outer, vec![Event::new("outer"), Event::new("outer")]);
+    }
+
+    #[test]
+    fn could_restore_after_panic() {
+        let result = std::panic::catch_unwind(|| collect(|| panic!("boom")));
+        assert!(result.is_err());
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn could_render_json() {
+        let event = Event::new("digits")
+            .at_line(2)
+            .field("text", "a\"1")
+            .field("value", 11);
+        assert_eq!(
+            event.to_json(r#""day":1"#),
+            r#"{"day":1,"line":2,"kind":"digits","fields":{"text":"a\"1","value":"11"}}"#
+        );
+        assert_eq!(
+            Event::new("done").to_json(""),
+            r#"{"line":null,"kind":"done","fields":{}}"#
+        );
+        assert_eq!(event.get("value"), Some("11"));
+    }
+}
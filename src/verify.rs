@@ -0,0 +1,109 @@
+use std::fs;
+
+use aoc::{answers::Expected, Answer, Part, Puzzle};
+
+use crate::registry;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Mismatch(Answer),
+    Failed(String),
+}
+
+#[derive(Debug)]
+pub struct Check<'a> {
+    pub expected: &'a Expected,
+    pub status: Status,
+}
+
+/// Solves each stored input once and compares every part recorded for it.
+pub fn check<'a>(puzzles: &[Puzzle], answers: &'a [Expected]) -> Vec<Check<'a>> {
+    let mut checks = vec![];
+    let mut done: Vec<(u16, u8, &Option<String>)> = vec![];
+    for expected in answers {
+        let key = (expected.year, expected.day, &expected.input);
+        if done.contains(&key) {
+            continue;
+        }
+        done.push(key);
+        let group: Vec<&Expected> = answers
+            .iter()
+            .filter(|other| (other.year, other.day, &other.input) == key)
+            .collect();
+        let parts: Vec<Part> = group.iter().map(|expected| expected.part).collect();
+        let solved = match registry::find(puzzles, expected.year, expected.day) {
+            Some(puzzle) => fs::read_to_string(expected.input_path())
+                .map_err(|e| format!("could not read {}: {}", expected.input_path().display(), e))
+                .and_then(|input| puzzle.solve(&input, &parts).map_err(|e| e.to_string())),
+            None => Err("no solver registered".to_string()),
+        };
+        for expected in group {
+            let status = match &solved {
+                Ok(answers) => match answers.iter().find(|(part, _)| *part == expected.part) {
+                    Some((_, answer)) if *answer == expected.answer => Status::Ok,
+                    Some((_, answer)) => Status::Mismatch(answer.clone()),
+                    None => Status::Failed("part was not solved".to_string()),
+                },
+                Err(e) => Status::Failed(e.clone()),
+            };
+            checks.push(Check { expected, status });
+        }
+    }
+    checks
+}
+
+/// Registered puzzles with no stored answer for their default input.
+pub fn unchecked(puzzles: &[Puzzle], answers: &[Expected]) -> Vec<(u16, u8)> {
+    puzzles
+        .iter()
+        .filter(|puzzle| {
+            !answers.iter().any(|expected| {
+                (expected.year, expected.day) == (puzzle.year, puzzle.day)
+                    && expected.input.is_none()
+            })
+        })
+        .map(|puzzle| (puzzle.year, puzzle.day))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::answers::{self, ANSWERS_FILE};
+
+    #[test]
+    fn could_match_stored_answers() {
+        let puzzles = registry::puzzles();
+        let answers = answers::load(ANSWERS_FILE).unwrap();
+        assert_eq!(unchecked(&puzzles, &answers), vec![]);
+        for check in check(&puzzles, &answers) {
+            let expected = check.expected;
+            assert_eq!(
+                check.status,
+                Status::Ok,
+                "{} day {} part {}",
+                expected.year,
+                expected.day,
+                expected.part
+            );
+        }
+    }
+
+    #[test]
+    fn could_report_mismatch() {
+        let puzzles = registry::puzzles();
+        let answers =
+            answers::parse(ANSWERS_FILE, "2023 6 1 2449062\n2023 6 2 1\n1999 1 1 1").unwrap();
+        let statuses: Vec<_> = check(&puzzles, &answers)
+            .into_iter()
+            .map(|check| check.status)
+            .collect();
+        assert_eq!(statuses[0], Status::Ok);
+        assert_eq!(statuses[1], Status::Mismatch(Answer::from(33149631u64)));
+        assert_eq!(
+            statuses[2],
+            Status::Failed("no solver registered".to_string())
+        );
+    }
+}
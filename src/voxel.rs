@@ -0,0 +1,306 @@
+use std::{
+    collections::{hash_set, HashSet},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// A unit cube, named by its corner.
+pub type Voxel = Point3;
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+    /// The six face directions: +x, -x, +y, -y, +z, -z.
+    pub const FACES: [Self; 6] = [
+        Self::new(1, 0, 0),
+        Self::new(-1, 0, 0),
+        Self::new(0, 1, 0),
+        Self::new(0, -1, 0),
+        Self::new(0, 0, 1),
+        Self::new(0, 0, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn componentwise_min(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn componentwise_max(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// Neighbours that differ in at most `axes` coordinates, each by one.
+    fn neighbours(self, axes: usize) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Self::new(x, y, z))))
+            .filter(move |offset| (1..=axes as u32).contains(&offset.manhattan(&Self::ORIGIN)))
+            .map(move |offset| self + offset)
+    }
+
+    /// Voxels sharing a face.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        Self::FACES.into_iter().map(move |face| self + face)
+    }
+
+    /// Voxels sharing a face or an edge.
+    pub fn neighbours18(self) -> impl Iterator<Item = Self> {
+        self.neighbours(2)
+    }
+
+    /// Voxels sharing a face, an edge or a corner.
+    pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+        self.neighbours(3)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i32> for Point3 {
+    type Output = Self;
+
+    fn mul(self, factor: i32) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// The smallest box holding some points, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl BoundingBox {
+    /// `None` when there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |bounds, point| bounds.include(point),
+        ))
+    }
+
+    pub fn include(&self, point: Point3) -> Self {
+        Self {
+            min: self.min.componentwise_min(&point),
+            max: self.max.componentwise_max(&point),
+        }
+    }
+
+    /// Grows the box by `by` on every side.
+    pub fn expand(&self, by: i32) -> Self {
+        let by = Point3::new(by, by, by);
+        Self {
+            min: self.min - by,
+            max: self.max + by,
+        }
+    }
+
+    pub fn contains(&self, point: Point3) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+            && self.min.z <= point.z
+            && point.z <= self.max.z
+    }
+
+    /// Voxels along each axis.
+    pub fn size(&self) -> Point3 {
+        self.max - self.min + Point3::new(1, 1, 1)
+    }
+
+    pub fn volume(&self) -> u64 {
+        let size = self.size();
+        size.x as u64 * size.y as u64 * size.z as u64
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let (min, max) = (self.min, self.max);
+        (min.x..=max.x).flat_map(move |x| {
+            (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| Point3::new(x, y, z)))
+        })
+    }
+}
+
+/// Filled voxels, backed by a hash set so coordinates may be negative or sparse.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VoxelSet {
+    voxels: HashSet<Voxel>,
+}
+
+impl VoxelSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `false` if the voxel was already there.
+    pub fn insert(&mut self, voxel: Voxel) -> bool {
+        self.voxels.insert(voxel)
+    }
+
+    pub fn remove(&mut self, voxel: &Voxel) -> bool {
+        self.voxels.remove(voxel)
+    }
+
+    pub fn contains(&self, voxel: &Voxel) -> bool {
+        self.voxels.contains(voxel)
+    }
+
+    pub fn len(&self) -> usize {
+        self.voxels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.voxels.is_empty()
+    }
+
+    pub fn iter(&self) -> hash_set::Iter<'_, Voxel> {
+        self.voxels.iter()
+    }
+
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        BoundingBox::from_points(self.voxels.iter().copied())
+    }
+
+    /// Faces not shared with another voxel of the set.
+    pub fn surface_area(&self) -> usize {
+        self.voxels
+            .iter()
+            .flat_map(|voxel| voxel.neighbours6())
+            .filter(|neighbour| !self.contains(neighbour))
+            .count()
+    }
+}
+
+impl FromIterator<Voxel> for VoxelSet {
+    fn from_iter<I: IntoIterator<Item = Voxel>>(iter: I) -> Self {
+        Self {
+            voxels: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a VoxelSet {
+    type Item = &'a Voxel;
+    type IntoIter = hash_set::Iter<'a, Voxel>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_count_neighbours() {
+        let p = Point3::new(1, -2, 3);
+        assert_eq!(p.neighbours6().count(), 6);
+        assert_eq!(p.neighbours18().count(), 18);
+        assert_eq!(p.neighbours26().count(), 26);
+        assert!(p.neighbours6().all(|n| n.manhattan(&p) == 1));
+        assert!(p.neighbours18().all(|n| (1..=2).contains(&n.manhattan(&p))));
+        assert!(!p.neighbours26().any(|n| n == p));
+    }
+
+    #[test]
+    fn could_do_arithmetic() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(-1, 0, 5);
+        assert_eq!(a + b, Point3::new(0, 2, 8));
+        assert_eq!(a - b, Point3::new(2, 2, -2));
+        assert_eq!(-a * 2, Point3::new(-2, -4, -6));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(a.manhattan(&b), 6);
+    }
+
+    #[test]
+    fn could_bound_points() {
+        let bounds =
+            BoundingBox::from_points([Point3::new(-1, 2, 0), Point3::new(3, -4, 1)]).unwrap();
+        assert_eq!(bounds.min, Point3::new(-1, -4, 0));
+        assert_eq!(bounds.max, Point3::new(3, 2, 1));
+        assert_eq!(bounds.size(), Point3::new(5, 7, 2));
+        assert_eq!(bounds.volume(), 70);
+        assert_eq!(bounds.points().count(), 70);
+        assert!(bounds.contains(Point3::ORIGIN));
+        assert!(!bounds.contains(Point3::new(0, 0, 2)));
+        assert!(bounds.expand(1).contains(Point3::new(0, 0, 2)));
+        assert_eq!(BoundingBox::from_points([]), None);
+    }
+
+    #[test]
+    fn could_measure_surface_area() {
+        let mut set: VoxelSet = [Point3::new(1, 1, 1), Point3::new(2, 1, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.surface_area(), 10);
+        assert!(!set.insert(Point3::new(1, 1, 1)));
+        assert!(set.remove(&Point3::new(2, 1, 1)));
+        assert_eq!(set.surface_area(), 6);
+        assert_eq!(set.len(), 1);
+    }
+}
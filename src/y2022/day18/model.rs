@@ -0,0 +1,109 @@
+#[cfg(test)]
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+#[cfg(test)]
+use crate::voxel::Point3;
+use crate::voxel::{BoundingBox, VoxelSet};
+
+/// Whether looking from `air` along each of the six axes hits lava before leaving `bounds`.
+#[cfg(test)]
+pub fn is_enclosed(
+    air: Point3,
+    droplets: &VoxelSet,
+    bounds: &BoundingBox,
+    cache: Option<&mut HashMap<Point3, bool>>,
+) -> bool {
+    if let Some(cache) = &cache {
+        if let Some(result) = cache.get(&air) {
+            return *result;
+        }
+    }
+    let rs = Point3::FACES.iter().all(|&direction| {
+        let mut point = air + direction;
+        while bounds.contains(point) {
+            if droplets.contains(&point) {
+                return true;
+            }
+            point += direction;
+        }
+        false
+    });
+    if let Some(cache) = cache {
+        cache.insert(air, rs);
+    }
+    rs
+}
+
+/// Faces of `droplet` that touch air.
+#[cfg(test)]
+pub fn get_empty_droplets(
+    droplet: Point3,
+    droplets: &VoxelSet,
+) -> impl Iterator<Item = Point3> + '_ {
+    droplet
+        .neighbours6()
+        .filter(|neighbour| !droplets.contains(neighbour))
+}
+
+/// The air reachable from outside, filled from a corner of the droplets' bounds grown by one,
+/// along with those grown bounds.
+pub fn exterior_air(droplets: &VoxelSet) -> Option<(VoxelSet, BoundingBox)> {
+    let bounds = droplets.bounds()?.expand(1);
+    let mut outside = VoxelSet::default();
+    let mut queue = VecDeque::from([bounds.min]);
+    outside.insert(bounds.min);
+    while let Some(air) = queue.pop_front() {
+        for neighbour in air.neighbours6() {
+            if !droplets.contains(&neighbour)
+                && bounds.contains(neighbour)
+                && outside.insert(neighbour)
+            {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    Some((outside, bounds))
+}
+
+/// Counts the faces that touch [`exterior_air`].
+pub fn exterior_surface(droplets: &VoxelSet) -> usize {
+    let Some((outside, _)) = exterior_air(droplets) else {
+        return 0;
+    };
+    outside
+        .iter()
+        .flat_map(|air| air.neighbours6())
+        .filter(|neighbour| droplets.contains(neighbour))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_get_empty_droplets() {
+        let droplets: VoxelSet = [
+            Point3::new(0, 0, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 1, 0),
+            Point3::new(1, 0, 0),
+            Point3::new(1, 1, 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(get_empty_droplets(Point3::ORIGIN, &droplets).count(), 3);
+    }
+
+    #[test]
+    fn could_check_if_enclosed() {
+        let air = Point3::new(1, 1, 1);
+        let droplets: VoxelSet = air.neighbours6().collect();
+        let bounds = droplets.bounds().unwrap();
+        assert!(is_enclosed(air, &droplets, &bounds, None));
+        let mut droplets = droplets;
+        droplets.remove(&Point3::new(1, 1, 0));
+        assert!(!is_enclosed(air, &droplets, &bounds, None));
+    }
+}
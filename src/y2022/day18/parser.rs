@@ -0,0 +1,24 @@
+use crate::{error::IResult, voxel::Point3};
+use nom::{character::complete::i32 as parse_i32, Parser};
+use nom_supreme::{tag::complete::tag, ParserExt};
+
+pub fn parse_droplet(input: &str) -> IResult<'_, Point3> {
+    let (input, x) = parse_i32.context("x").parse(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, y) = parse_i32.context("y").parse(input)?;
+    let (input, _) = tag(",")(input)?;
+    let (input, z) = parse_i32.context("z").parse(input)?;
+    Ok((input, Point3::new(x, y, z)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_parse_part() {
+        let input = "3,2,5";
+        let expected = Point3::new(3, 2, 5);
+        assert_eq!(parse_droplet(input).unwrap().1, expected);
+    }
+}
@@ -0,0 +1,98 @@
+use std::io::BufRead;
+
+use crate::{
+    error::parse_lines,
+    stream::{for_each_line, parse_line},
+    Answer, Result, Solver,
+};
+
+pub mod parser;
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve(input)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve2(input)?.into())
+    }
+}
+
+pub fn solve(input: &str) -> Result<u32> {
+    let values = parse_lines(input, parser::parse)?;
+    explain(input, &values);
+    Ok(values.into_iter().sum())
+}
+
+pub fn solve2(input: &str) -> Result<u32> {
+    let values = parse_lines(input, parser::parse2)?;
+    explain(input, &values);
+    Ok(values.into_iter().sum())
+}
+
+/// Which digits each line's calibration value was made of.
+fn explain(input: &str, values: &[u32]) {
+    if !crate::trace::enabled() {
+        return;
+    }
+    for (i, (text, value)) in input.lines().zip(values).enumerate() {
+        crate::trace!(line: i + 1, "digits", text = text, first = value / 10, last = value % 10);
+    }
+}
+
+/// Both parts in one pass over `reader`, holding a single line at a time.
+pub fn solve_stream(reader: impl BufRead) -> Result<(u32, u32)> {
+    let (mut part1, mut part2) = (0, 0);
+    for_each_line(reader, |number, line| {
+        part1 += parse_line(number, line, parser::parse(line))?;
+        part2 += parse_line(number, line, parser::parse2(line))?;
+        Ok(())
+    })?;
+    Ok((part1, part2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sample_tests!(Day1 {
+        could_solve_part_1: One { file "sample.txt" => 142 }
+        could_solve_part_2: Two { file "sample2.txt" => 281 }
+    });
+
+    #[test]
+    fn could_solve_stream() {
+        let input = include_str!("sample.txt");
+        let expected = (solve(input).unwrap(), solve2(input).unwrap());
+        assert_eq!(solve_stream(input.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn could_explain_picked_digits() {
+        let (_, events) = crate::trace::collect(|| solve2(include_str!("sample2.txt")));
+        let events: Vec<_> = events
+            .iter()
+            .take(3)
+            .map(|event| event.to_string())
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                "line 1: digits text=two1nine first=2 last=9",
+                "line 2: digits text=eightwothree first=8 last=3",
+                "line 3: digits text=abcone2threexyz first=1 last=3",
+            ]
+        );
+    }
+}
@@ -1,37 +1,42 @@
+use crate::error::IResult;
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take},
+    bytes::complete::take,
     character::complete::alpha0,
     combinator::{map, map_res},
-    IResult,
+    Parser,
 };
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
 
 fn parse_number(input: &str) -> Result<u32, std::num::ParseIntError> {
     input.parse::<u32>()
 }
 
-fn parse_2nd_part(input: &str) -> IResult<&str, u32> {
+fn parse_2nd_part(input: &str) -> IResult<'_, u32> {
     let (input, _) = alpha0(input)?;
     let (input, v) = map_res(take(1usize), parse_number)(input)?;
     Ok((input, v))
 }
 
-pub fn parse(input: &str) -> IResult<&str, u32> {
+pub fn parse(input: &str) -> IResult<'_, u32> {
     let (input, _) = alpha0(input)?;
-    let (mut remaining, initial) = map_res(take(1usize), parse_number)(input)?;
+    let (mut remaining, initial) = map_res(take(1usize), parse_number)
+        .context("first digit")
+        .parse(input)?;
     let mut last = None;
     while let Ok((input, v)) = parse_2nd_part(remaining) {
         last = Some(v);
         remaining = input;
     }
+    let (remaining, _) = alpha0(remaining)?;
     if let Some(last) = last {
-        Ok((input, initial * 10 + last))
+        Ok((remaining, initial * 10 + last))
     } else {
-        Ok((input, initial * 10 + initial))
+        Ok((remaining, initial * 10 + initial))
     }
 }
 
-pub fn parse_text_number(input: &str) -> IResult<&str, u32> {
+pub fn parse_text_number(input: &str) -> IResult<'_, u32> {
     let (input, v) = alt((
         map(tag("one"), |_| 1),
         map(tag("two"), |_| 2),
@@ -46,7 +51,7 @@ pub fn parse_text_number(input: &str) -> IResult<&str, u32> {
     Ok((input, v))
 }
 
-pub fn parse2(input: &str) -> IResult<&str, u32> {
+pub fn parse2(input: &str) -> IResult<'_, u32> {
     let (initial, mut remaining) = first_number(input)?;
     let mut last = None;
     while !remaining.is_empty() {
@@ -63,11 +68,11 @@ pub fn parse2(input: &str) -> IResult<&str, u32> {
     }
 }
 
-fn first_number(remaining: &str) -> Result<(u32, &str), nom::Err<nom::error::Error<&str>>> {
+fn first_number(remaining: &str) -> Result<(u32, &str), nom::Err<ErrorTree<&str>>> {
     let mut remaining = remaining;
     let mut parse_result = alt((parse_text_number, map_res(take(1usize), parse_number)))(remaining);
     while parse_result.is_err() {
-        (remaining, _) = take(1usize)(remaining)?;
+        (remaining, _) = take(1usize).context("digit or spelled number").parse(remaining)?;
         parse_result = alt((parse_text_number, map_res(take(1usize), parse_number)))(remaining);
     }
     let (_, initial) = parse_result?;
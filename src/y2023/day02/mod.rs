@@ -0,0 +1,136 @@
+use std::io::BufRead;
+
+use crate::{
+    error::parse_lines,
+    stream::{for_each_line, parse_line},
+    Answer, Result, Solver,
+};
+pub use parser::{Game, Hint};
+
+pub mod parser;
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part_1(input)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part_2(input)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game<'_>>> {
+    parse_lines(input, parser::parse_game_line)
+}
+
+pub fn is_possible(game: &Game) -> bool {
+    disqualifying_hint(game).is_none()
+}
+
+/// The first hint showing more cubes than the bag holds, with the 0-based draw it is in.
+fn disqualifying_hint<'a>(game: &'a Game) -> Option<(usize, &'a Hint<'a>)> {
+    game.hint.iter().enumerate().find_map(|(draw, hints)| {
+        hints
+            .iter()
+            .find(|hint| !hint.is_valid())
+            .map(|hint| (draw, hint))
+    })
+}
+
+pub fn power(game: &Game) -> usize {
+    let mut max_green = 0;
+    let mut max_red = 0;
+    let mut max_blue = 0;
+    for hint in game.hint.iter().flatten() {
+        match hint.color {
+            "blue" => max_blue = max_blue.max(hint.count),
+            "green" => max_green = max_green.max(hint.count),
+            "red" => max_red = max_red.max(hint.count),
+            _ => (),
+        }
+    }
+    max_blue * max_green * max_red
+}
+
+pub fn solve_part_1(games: &[Game]) -> Result<usize> {
+    let mut sum = 0;
+    for (i, game) in games.iter().enumerate() {
+        match disqualifying_hint(game) {
+            Some((draw, hint)) => {
+                crate::trace!(line: i + 1, "impossible", game = game.id, draw = draw + 1, hint = hint)
+            }
+            None => {
+                crate::trace!(line: i + 1, "possible", game = game.id);
+                sum += game.id;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+pub fn solve_part_2(games: &[Game]) -> Result<usize> {
+    Ok(games.iter().map(power).sum())
+}
+
+/// Both parts in one pass over `reader`, holding a single game at a time.
+pub fn solve_stream(reader: impl BufRead) -> Result<(usize, usize)> {
+    let (mut part1, mut part2) = (0, 0);
+    for_each_line(reader, |number, line| {
+        let game = parse_line(number, line, parser::parse_game_line(line))?;
+        if is_possible(&game) {
+            part1 += game.id;
+        }
+        part2 += power(&game);
+        Ok(())
+    })?;
+    Ok((part1, part2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sample_tests!(Day2 {
+        could_solve_part_1: One { file "sample.txt" => 8 }
+        could_solve_part_2: Two { file "sample.txt" => 2286 }
+    });
+
+    #[test]
+    fn could_solve_stream() {
+        let input = include_str!("sample.txt");
+        let parsed = Day2::parse(input).unwrap();
+        let expected = (
+            solve_part_1(&parsed).unwrap(),
+            solve_part_2(&parsed).unwrap(),
+        );
+        assert_eq!(solve_stream(input.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn could_explain_disqualified_games() {
+        let games = Day2::parse(include_str!("sample.txt")).unwrap();
+        let (_, events) = crate::trace::collect(|| solve_part_1(&games));
+        let events: Vec<_> = events.iter().map(|event| event.to_string()).collect();
+        assert_eq!(
+            events,
+            vec![
+                "line 1: possible game=1",
+                "line 2: possible game=2",
+                "line 3: impossible game=3 draw=1 hint=\"20 red\"",
+                "line 4: impossible game=4 draw=3 hint=\"15 blue\"",
+                "line 5: possible game=5",
+            ]
+        );
+    }
+}
@@ -1,22 +1,17 @@
-use anyhow::{anyhow, Result};
-use aoc::space0_surrounded;
+use crate::{error::IResult, space0_surrounded};
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_till},
+    bytes::complete::take_till,
     character::complete::{u32 as parse_u32},
     multi::{separated_list1},
-    IResult,
+    Parser,
 };
+use nom_supreme::{tag::complete::tag, ParserExt};
 
-pub fn parse_game(input: &'static str) -> Result<Game> {
-    let (_, game) = parse_game_line(input).map_err(|e| anyhow!(e))?;
-    Ok(game)
-}
-
-fn parse_game_line(input: &'static str) -> IResult<&str, Game> {
+pub fn parse_game_line(input: &str) -> IResult<'_, Game<'_>> {
     let (input, _) = tag("Game ")(input)?;
-    let (input, id) = parse_u32(input)?;
-    let (input, hints) = separated_list1(tag(";"), parse_hints)(input)?;
+    let (input, id) = parse_u32.context("game id").parse(input)?;
+    let (input, hints) = separated_list1(tag(";"), parse_hints.context("hint set")).parse(input)?;
     Ok((
         input,
         Game {
@@ -26,10 +21,12 @@ fn parse_game_line(input: &'static str) -> IResult<&str, Game> {
     ))
 }
 
-fn parse_hint(input: &'static str) -> IResult<&str, Hint> {
+fn parse_hint(input: &str) -> IResult<'_, Hint<'_>> {
     let (input, _) = take_till(|c: char| c.is_numeric())(input)?;
-    let (input, count) = space0_surrounded(parse_u32)(input)?;
-    let (input, color) = alt((tag("blue"), tag("green"), tag("red")))(input)?;
+    let (input, count) = space0_surrounded(parse_u32.context("cube count"))(input)?;
+    let (input, color) = alt((tag("blue"), tag("green"), tag("red")))
+        .context("cube color")
+        .parse(input)?;
     Ok((
         input,
         Hint {
@@ -39,18 +36,24 @@ fn parse_hint(input: &'static str) -> IResult<&str, Hint> {
     ))
 }
 
-fn parse_hints(input: &'static str) -> IResult<&str, Vec<Hint>> {
+fn parse_hints(input: &str) -> IResult<'_, Vec<Hint<'_>>> {
     let (input, hints) = separated_list1(tag(","), parse_hint)(input)?;
     Ok((input, hints))
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Hint {
-    pub color: &'static str,
+pub struct Hint<'a> {
+    pub color: &'a str,
     pub count: usize,
 }
 
-impl Hint {
+impl std::fmt::Display for Hint<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.count, self.color)
+    }
+}
+
+impl Hint<'_> {
     pub fn is_valid(&self) -> bool {
         match self.color {
             "blue" => self.count <= 14,
@@ -62,9 +65,9 @@ impl Hint {
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Game {
+pub struct Game<'a> {
     pub id: usize,
-    pub hint: Vec<Vec<Hint>>,
+    pub hint: Vec<Vec<Hint<'a>>>,
 }
 
 #[cfg(test)]
@@ -102,7 +105,7 @@ mod tests {
     #[test]
     fn could_parse_game() {
         let input = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
-        let valve = parse_game(input).unwrap();
+        let valve = parse_game_line(input).unwrap().1;
         let expected = Game {
             id: 2,
             hint: vec![
@@ -150,7 +153,7 @@ mod tests {
         use nom::bytes::complete::tag;
         use nom::multi::many1;
 
-        fn parser(s: &str) -> IResult<&str, Vec<&str>> {
+        fn parser(s: &str) -> nom::IResult<&str, Vec<&str>> {
             many1(tag("abc"))(s)
         }
 
@@ -0,0 +1,125 @@
+use crate::{
+    render::{Cell, Color, Frame, Style},
+    Answer, Result, Solver,
+};
+use parser::is_symbol;
+pub use parser::Schematic;
+
+pub mod parser;
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+
+    fn visualize(input: &Self::Input<'_>) -> Result<Vec<Frame>> {
+        Ok(vec![highlight(input)])
+    }
+}
+
+pub fn parse(input: &str) -> Result<Schematic> {
+    parser::parse(input)
+}
+
+pub fn part1(schematic: &Schematic) -> Result<u32> {
+    let rs = schematic
+        .numbers
+        .iter()
+        .filter(|number| number.is_part(&schematic.grid))
+        .map(|number| number.value)
+        .sum();
+    Ok(rs)
+}
+
+pub fn part2(schematic: &Schematic) -> Result<usize> {
+    let rs = schematic
+        .grid
+        .points()
+        .filter_map(|point| schematic.get_gear_ratio(point))
+        .sum();
+    Ok(rs)
+}
+
+/// Part numbers in green and gears in yellow; numbers that are not parts fade out.
+/// Without colours, those numbers are blanked and gears become `G`.
+pub fn highlight(schematic: &Schematic) -> Frame {
+    let mut canvas = schematic.grid.map(|&c| match c {
+        c if is_symbol(c) => Cell::styled(c, Style::PLAIN.fg(Color::Cyan)),
+        c => Cell::new(c),
+    });
+    let mut parts = 0;
+    for number in &schematic.numbers {
+        let style = if number.is_part(&schematic.grid) {
+            parts += 1;
+            Style::PLAIN.fg(Color::Green).bold()
+        } else {
+            Style::PLAIN.fg(Color::Grey).plain(' ')
+        };
+        for point in number.points() {
+            canvas[point].style = style;
+        }
+    }
+    let mut gears = 0;
+    for point in schematic.grid.points() {
+        if schematic.get_gear_ratio(point).is_some() {
+            gears += 1;
+            canvas[point].style = Style::PLAIN.fg(Color::Yellow).bold().plain('G');
+        }
+    }
+    Frame {
+        title: format!(
+            "{} of {} numbers are part numbers, {} gears",
+            parts,
+            schematic.numbers.len(),
+            gears
+        ),
+        canvas,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::{render_frames, Mode};
+
+    use super::*;
+
+    crate::sample_tests!(Day3 {
+        could_solve_part1: One { file "sample.txt" => 4361 }
+        could_solve_part2: Two { file "sample.txt" => 467835 }
+    });
+
+    #[test]
+    fn could_highlight_parts_and_gears() {
+        let schematic = Day3::parse(include_str!("sample.txt")).unwrap();
+        let frames = Day3::visualize(&schematic).unwrap();
+        assert_eq!(
+            render_frames(&frames, Mode::Plain),
+            "8 of 10 numbers are part numbers, 2 gears\n\
+             467..   ..\n\
+             ...G......\n\
+             ..35..633.\n\
+             ......#...\n\
+             617*......\n\
+             .....+.  .\n\
+             ..592.....\n\
+             ......755.\n\
+             ...$.G....\n\
+             .664.598..\n"
+        );
+    }
+}
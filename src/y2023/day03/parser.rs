@@ -0,0 +1,144 @@
+use crate::{
+    error::{Error, Position},
+    grid::Point,
+    Grid, Result,
+};
+
+pub fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Number {
+    pub value: u32,
+    width: usize,
+    x: usize,
+    y: usize,
+}
+
+impl Number {
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.x..self.x + self.width).map(|x| (x, self.y))
+    }
+
+    pub fn is_part(&self, grid: &Grid<char>) -> bool {
+        self.points()
+            .flat_map(|point| grid.neighbours8(point))
+            .any(|point| is_symbol(grid[point]))
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<Number>,
+    /// Index into `numbers` of the number covering each cell.
+    owners: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    /// Product of the two numbers next to `point`, if it is a gear on the grid.
+    pub fn get_gear_ratio(&self, point: Point) -> Option<usize> {
+        if self.grid.get(point) != Some(&'*') {
+            return None;
+        }
+        let mut adjacent: Vec<usize> = self
+            .grid
+            .neighbours8(point)
+            .filter_map(|point| self.owners[point])
+            .collect();
+        adjacent.sort();
+        adjacent.dedup();
+        match adjacent[..] {
+            [a, b] => Some(self.numbers[a].value as usize * self.numbers[b].value as usize),
+            _ => None,
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Schematic> {
+    let grid = Grid::parse(input)?;
+    let mut numbers = vec![];
+    let mut owners = Grid::new(grid.width(), grid.height(), None);
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let width = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if width == 0 {
+                x += 1;
+                continue;
+            }
+            let digits: String = row[x..x + width].iter().collect();
+            let value = digits.parse().map_err(|e| Error {
+                position: Some(Position {
+                    line: y + 1,
+                    column: x + 1,
+                }),
+                source_line: Some(row.iter().collect()),
+                ..Error::new(format!("{} is not a part number: {}", digits, e))
+            })?;
+            let number = Number { value, width, x, y };
+            for point in number.points() {
+                owners[point] = Some(numbers.len());
+            }
+            numbers.push(number);
+            x += width;
+        }
+    }
+    Ok(Schematic {
+        grid,
+        numbers,
+        owners,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_parse_numbers() {
+        let input = ".617*.....33.";
+        let expected = vec![
+            Number {
+                value: 617,
+                width: 3,
+                x: 1,
+                y: 0,
+            },
+            Number {
+                value: 33,
+                width: 2,
+                x: 10,
+                y: 0,
+            },
+        ];
+        let schematic = parse(input).unwrap();
+        assert_eq!(schematic.numbers, expected);
+        assert!(is_symbol(schematic.grid[(4, 0)]));
+    }
+
+    #[test]
+    fn could_check_if_part() {
+        let schematic = parse(".617*.....33.").unwrap();
+        assert!(schematic.numbers[0].is_part(&schematic.grid));
+        assert!(!schematic.numbers[1].is_part(&schematic.grid));
+    }
+
+    #[test]
+    fn could_get_gear_ratio() {
+        let schematic = parse(".617*.....33.").unwrap();
+        assert_eq!(schematic.get_gear_ratio((4, 0)), None);
+        let schematic = parse("467.\n...*\n..35").unwrap();
+        assert_eq!(schematic.get_gear_ratio((3, 1)), Some(16345));
+        assert_eq!(schematic.get_gear_ratio((2, 1)), None);
+        assert_eq!(schematic.get_gear_ratio((4, 1)), None);
+        assert_eq!(schematic.get_gear_ratio((0, 9)), None);
+    }
+
+    #[test]
+    fn could_report_overflow() {
+        let err = parse("............\n.99999999999").unwrap_err();
+        assert_eq!(err.position, Some(Position { line: 2, column: 2 }));
+    }
+}
@@ -0,0 +1,155 @@
+use std::{collections::VecDeque, io::BufRead};
+
+use crate::{
+    error::parse_lines,
+    stream::{for_each_line, parse_line},
+    Answer, Result, Solver,
+};
+pub use parser::Card;
+
+pub mod parser;
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve(input)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve2(input)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>> {
+    parse_lines(input, parser::parse_card)
+}
+
+pub fn solve(cards: &[Card]) -> Result<u32> {
+    Ok(cards.iter().map(|card| card.get_score()).sum())
+}
+
+/// Extra copies won by the cards read so far, for the cards still to come. Each card only
+/// wins copies of the next few cards, so this stays as long as the most matches on a card.
+#[derive(Default)]
+struct Copies {
+    pending: VecDeque<u32>,
+}
+
+impl Copies {
+    /// How many of `card` we end up with, passing its winnings on to the next cards.
+    fn take(&mut self, card: &Card) -> u32 {
+        let total = 1 + self.pending.pop_front().unwrap_or(0);
+        let won = card.get_next_cards().len();
+        if self.pending.len() < won {
+            self.pending.resize(won, 0);
+        }
+        for pending in self.pending.iter_mut().take(won) {
+            *pending += total;
+        }
+        total
+    }
+}
+
+pub fn solve2(cards: &[Card]) -> Result<u32> {
+    let mut copies = Copies::default();
+    let mut sum = 0;
+    // Copies never go past the end of the table, whatever the matches say.
+    let last = cards.last().map_or(0, |card| card.id);
+    for (i, card) in cards.iter().enumerate() {
+        let held = copies.take(card);
+        crate::trace!(
+            line: i + 1,
+            "copies",
+            card = card.id,
+            held = held,
+            copied = ids(&card.get_next_cards().into_iter().filter(|&id| id <= last).collect::<Vec<_>>()),
+        );
+        sum += held;
+    }
+    Ok(sum)
+}
+
+/// Consecutive card ids as `2-5`.
+fn ids(ids: &[u32]) -> String {
+    match ids {
+        [] => "none".to_string(),
+        [id] => id.to_string(),
+        [first, .., last] => format!("{}-{}", first, last),
+    }
+}
+
+/// Both parts in one pass over `reader`, holding a single card at a time.
+pub fn solve_stream(reader: impl BufRead) -> Result<(u32, u32)> {
+    let (mut part1, mut part2) = (0, 0);
+    let mut copies = Copies::default();
+    for_each_line(reader, |number, line| {
+        let card = parse_line(number, line, parser::parse_card(line))?;
+        part1 += card.get_score();
+        part2 += copies.take(&card);
+        Ok(())
+    })?;
+    Ok((part1, part2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sample_tests!(Day4 {
+        could_solve_part1: One { file "sample.txt" => 13 }
+        could_solve_part2: Two { file "sample.txt" => 30 }
+    });
+
+    #[test]
+    fn could_report_bad_line() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2 13 32 | 61 30";
+        let err = parse(input).unwrap_err();
+        assert_eq!(
+            err.position,
+            Some(crate::error::Position { line: 2, column: 8 })
+        );
+    }
+
+    #[test]
+    fn could_solve_stream() {
+        let input = include_str!("sample.txt");
+        let parsed = Day4::parse(input).unwrap();
+        let expected = (solve(&parsed).unwrap(), solve2(&parsed).unwrap());
+        assert_eq!(solve_stream(input.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn could_explain_copies() {
+        let cards = Day4::parse(include_str!("sample.txt")).unwrap();
+        let (_, events) = crate::trace::collect(|| solve2(&cards));
+        let events: Vec<_> = events.iter().map(|event| event.to_string()).collect();
+        assert_eq!(
+            events,
+            vec![
+                "line 1: copies card=1 held=1 copied=2-5",
+                "line 2: copies card=2 held=2 copied=3-4",
+                "line 3: copies card=3 held=4 copied=4-5",
+                "line 4: copies card=4 held=8 copied=5",
+                "line 5: copies card=5 held=14 copied=none",
+                "line 6: copies card=6 held=1 copied=none",
+            ]
+        );
+        let cards = Day4::parse("Card 1: 1 2 3 | 1 2 3\nCard 2: 4 5 | 4 6").unwrap();
+        let (_, events) = crate::trace::collect(|| solve2(&cards));
+        let copied: Vec<_> = events
+            .iter()
+            .map(|event| event.get("copied").unwrap())
+            .collect();
+        assert_eq!(copied, vec!["2", "none"]);
+    }
+}
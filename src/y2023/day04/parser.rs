@@ -1,5 +1,6 @@
-use nom::{IResult, bytes::complete::tag, multi::separated_list1, character::complete};
-use aoc::space0_surrounded;
+use nom::{character::complete, Parser};
+use nom_supreme::{tag::complete::tag, ParserExt};
+use crate::{error::IResult, parse::numbers, space0_surrounded};
 
 #[derive(Debug, PartialEq)]
 pub struct Card {
@@ -30,19 +31,13 @@ impl Card {
     }
 }
 
-pub fn parse_card(input: &'static str) -> IResult<&str, Card> {
+pub fn parse_card(input: &str) -> IResult<'_, Card> {
     let (input,_ ) = space0_surrounded(tag("Card"))(input)?;
-    let (input, id) = nom::character::complete::u32(input)?;
+    let (input, id) = complete::u32.context("card id").parse(input)?;
     let (input,_) = space0_surrounded(tag(":"))(input)?;
-    let (input, winning_numbers) = separated_list1(
-        complete::space1,
-        complete::u32,
-    )(input)?;
+    let (input, winning_numbers) = numbers(complete::u32).context("winning numbers").parse(input)?;
     let (input, _) = space0_surrounded(tag("|"))(input)?;
-    let (input, playing_numbers) = separated_list1(
-        complete::space1,
-        complete::u32,
-    )(input)?;
+    let (input, playing_numbers) = numbers(complete::u32).context("playing numbers").parse(input)?;
     Ok((
         input,
         Card {
@@ -0,0 +1,205 @@
+use crate::{
+    error::finish,
+    trace::{self, Event},
+    Answer, Result, Solver,
+};
+pub use parser::Map;
+
+pub mod parser;
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input<'a> = (Vec<i64>, Vec<Map<'a>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        let (seeds, maps) = input;
+        Ok(solve_part_1(seeds, maps)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        let (seeds, maps) = input;
+        Ok(solve_part_2(seeds, maps)?.into())
+    }
+}
+
+/// The seeds, then every map in the order they are applied.
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<Map<'_>>)> {
+    finish(input, parser::parse_input(input))
+}
+
+pub fn solve_part_1(seeds: &[i64], maps: &[Map<'_>]) -> Result<i64> {
+    let mut min_value = i64::MAX;
+    for &seed in seeds {
+        trace::emit(|| chain(seed, maps));
+        let mut src = seed;
+        for map in maps {
+            src = map.get_dest(src)
+        }
+        if src < min_value {
+            min_value = src;
+        }
+    }
+    Ok(min_value)
+}
+
+pub fn solve_part_2(seeds: &[i64], maps: &[Map<'_>]) -> Result<i64> {
+    let mut seed_ranges = parser::seed_ranges(seeds);
+    for map in maps {
+        seed_ranges = map.get_dest_from_range(&seed_ranges);
+        crate::trace!(
+            "ranges",
+            category = map.to(),
+            intervals = seed_ranges.intervals().len(),
+            lowest = seed_ranges.min().unwrap_or(i64::MAX),
+        );
+    }
+    Ok(seed_ranges.min().unwrap_or(i64::MAX))
+}
+
+/// What `seed` becomes in every category, all on the seeds line.
+fn chain(seed: i64, maps: &[Map<'_>]) -> Event {
+    let category = maps.first().map_or("seed", |map| map.from());
+    let mut event = Event::new("chain").at_line(1).field(category, seed);
+    let mut value = seed;
+    for map in maps {
+        value = map.get_dest(value);
+        event = event.field(map.to(), value);
+    }
+    event
+}
+
+/// Walks every seed one by one; far too slow for the real input, kept to cross-check [`solve_part_2`].
+#[cfg(test)]
+fn solve_part_2_brute_force(seeds: &[i64], maps: &[Map<'_>]) -> Result<i64> {
+    use rayon::prelude::*;
+
+    let seed_ranges = parser::seed_ranges(seeds);
+    let mut min_value = i64::MAX;
+    for range in seed_ranges.intervals() {
+        let new_low = (range.start..=range.end)
+            .into_par_iter()
+            .fold(
+                || i64::MAX,
+                |acc, seed| {
+                    let mut src = seed;
+                    for map in maps {
+                        src = map.get_dest(src)
+                    }
+                    if src < acc {
+                        src
+                    } else {
+                        acc
+                    }
+                },
+            )
+            .min()
+            .unwrap_or(i64::MAX);
+        if new_low < min_value {
+            min_value = new_low;
+        }
+    }
+    Ok(min_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    crate::sample_tests!(Day5 {
+        could_solve_part_1: One { file "sample.txt" => 35 }
+        could_solve_part_2: Two { file "sample.txt" => 46 }
+    });
+
+    #[test]
+    fn could_explain_chain() {
+        let (seeds, maps) = Day5::parse(include_str!("sample.txt")).unwrap();
+        let (_, events) = crate::trace::collect(|| solve_part_1(&seeds, &maps));
+        assert_eq!(events.len(), 4);
+        assert_eq!(
+            events[0].to_string(),
+            "line 1: chain seed=79 soil=81 fertilizer=81 water=81 light=74 temperature=78 \
+             humidity=78 location=82"
+        );
+        let lowest = events
+            .iter()
+            .map(|event| event.get("location").unwrap().parse::<i64>().unwrap())
+            .min();
+        assert_eq!(lowest, Some(35));
+    }
+
+    #[test]
+    fn could_match_brute_force() {
+        let input = include_str!("./sample.txt");
+        let (seeds, maps) = Day5::parse(input).unwrap();
+        assert_eq!(
+            solve_part_2_brute_force(&seeds, &maps).unwrap(),
+            solve_part_2(&seeds, &maps).unwrap()
+        );
+    }
+
+    #[test]
+    fn could_reject_trailing_garbage() {
+        let sample = include_str!("sample.txt");
+        let err = Day5::parse(&format!("{}56 93 x\n", sample)).unwrap_err();
+        assert_eq!(
+            err.position,
+            Some(crate::error::Position {
+                line: 34,
+                column: 1
+            })
+        );
+        let err = Day5::parse(&format!("{}\nfoo-to-bar map:\n", sample)).unwrap_err();
+        assert_eq!(err.message, "expected end of input");
+        assert_eq!(err.source_line.as_deref(), Some("foo-to-bar map:"));
+    }
+
+    const CATEGORIES: [&str; 6] = ["seed", "soil", "fertilizer", "water", "light", "location"];
+
+    /// A small almanac: a few seed ranges and up to five maps, whose entries may overlap.
+    fn almanac() -> impl Strategy<Value = String> {
+        let seeds = prop::collection::vec((0..60i64, 1..15i64), 1..4);
+        let entry = (0..80i64, 0..80i64, 1..20i64);
+        let maps = prop::collection::vec(prop::collection::vec(entry, 1..5), 1..6);
+        (seeds, maps).prop_map(|(seeds, maps)| {
+            let seeds: Vec<String> = seeds
+                .iter()
+                .map(|(start, len)| format!("{} {}", start, len))
+                .collect();
+            let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+            for (i, entries) in maps.iter().enumerate() {
+                almanac.push_str(&format!(
+                    "\n{}-to-{} map:\n",
+                    CATEGORIES[i],
+                    CATEGORIES[i + 1]
+                ));
+                for (dest, source, len) in entries {
+                    almanac.push_str(&format!("{} {} {}\n", dest, source, len));
+                }
+            }
+            almanac
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn could_match_brute_force_on_random_almanacs(input in almanac()) {
+            let (seeds, maps) = Day5::parse(&input).unwrap();
+            prop_assert_eq!(
+                solve_part_2(&seeds, &maps).unwrap(),
+                solve_part_2_brute_force(&seeds, &maps).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    error::IResult,
+    interval::{Interval, IntervalSet, RangeMap},
+    parse::{blank_line, blocks, header, numbers},
+};
+use nom::{
+    bytes::complete::take_until,
+    character::complete::{self, line_ending, not_line_ending},
+    error::{ErrorKind, FromExternalError},
+    multi::separated_list1,
+    sequence::{terminated, tuple},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+
+#[derive(Debug, PartialEq)]
+pub struct Map<'a> {
+    from: &'a str,
+    to: &'a str,
+    ranges: RangeMap,
+}
+
+fn parse_seed(input: &str) -> IResult<'_, Vec<i64>> {
+    header("seeds", numbers(complete::i64).context("seeds")).parse(input)
+}
+
+fn parse_entry(input: &str) -> IResult<'_, (Interval, i64)> {
+    let (rest, (dest, _, source, _, range)) = tuple((
+        complete::i64.context("destination start"),
+        complete::space1,
+        complete::i64.context("source start"),
+        complete::space1,
+        complete::i64.context("range length"),
+    ))
+    .parse(input)?;
+    let source = Interval::with_len(source, range).ok_or_else(|| {
+        nom::Err::Failure(ErrorTree::from_external_error(
+            input,
+            ErrorKind::Verify,
+            "a positive range length",
+        ))
+    })?;
+    Ok((rest, (source, dest - source.start)))
+}
+
+fn parse_map(input: &str) -> IResult<'_, Map<'_>> {
+    let (input, from) = take_until("-").context("map source").parse(input)?;
+    let (input, _) = tag("-to-")(input)?;
+    let (input, to) = take_until(" ").context("map destination").parse(input)?;
+    let (input, _) = terminated(not_line_ending, line_ending)(input)?;
+    let (input, entries) =
+        separated_list1(line_ending, parse_entry.context("map entry")).parse(input)?;
+    Ok((
+        input,
+        Map {
+            from,
+            to,
+            ranges: entries.into_iter().collect(),
+        },
+    ))
+}
+
+pub fn parse_input(input: &str) -> IResult<'_, (Vec<i64>, Vec<Map<'_>>)> {
+    let (input, seeds) = parse_seed(input)?;
+    let (input, _) = blank_line(input)?;
+    let (input, maps) = blocks(parse_map.context("map")).parse(input)?;
+    Ok((input, (seeds, maps)))
+}
+
+impl<'a> Map<'a> {
+    /// The category mapped from, `seed` in `seed-to-soil`.
+    pub fn from(&self) -> &'a str {
+        self.from
+    }
+
+    pub fn to(&self) -> &'a str {
+        self.to
+    }
+
+    pub fn get_dest(&self, source: i64) -> i64 {
+        self.ranges.get(source)
+    }
+
+    pub fn get_dest_from_range(&self, source: &IntervalSet) -> IntervalSet {
+        self.ranges.map_set(source)
+    }
+}
+
+/// Part 2 reads the seeds as `start length` pairs.
+pub fn seed_ranges(value: &[i64]) -> IntervalSet {
+    value
+        .chunks_exact(2)
+        .filter_map(|pair| Interval::with_len(pair[0], pair[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_parse_seed() {
+        let input = "seeds: 79 14 55 13";
+        let output = vec![79, 14, 55, 13];
+        assert_eq!(parse_seed(input).unwrap().1, output);
+    }
+
+    #[test]
+    fn could_parse_map() {
+        let input = r#"soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15"#;
+        let output = Map {
+            from: "soil",
+            to: "fertilizer",
+            ranges: [
+                (Interval::new(15, 51), -15),
+                (Interval::new(52, 53), -15),
+                (Interval::new(0, 14), 39),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        assert_eq!(parse_map(input).unwrap().1, output);
+    }
+
+    #[test]
+    fn could_parse_either_line_ending() {
+        let input = include_str!("./sample.txt");
+        let expected = parse_input(input).unwrap().1;
+        let crlf = input.replace('\n', "\r\n");
+        assert_eq!(parse_input(&crlf).unwrap().1, expected);
+        let trailing = format!("{}\n\n", input);
+        assert_eq!(parse_input(&trailing).unwrap().1, expected);
+    }
+
+    #[test]
+    fn could_reject_empty_entry() {
+        assert!(matches!(parse_entry("0 15 0"), Err(nom::Err::Failure(_))));
+    }
+
+    #[test]
+    fn could_get_destination() {
+        let input = 14;
+        let map = Map {
+            from: "soil",
+            to: "fertilizer",
+            ranges: [
+                (Interval::new(15, 52), -15),
+                (Interval::new(52, 54), -15),
+                (Interval::new(0, 15), 39),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let output = 53;
+        assert_eq!(map.get_dest(input), output);
+    }
+
+    #[test]
+    fn could_get_destination_from_range() {
+        let seed_range = seed_ranges(&[1, 15, 16, 33]);
+        let map = Map {
+            from: "soil",
+            to: "fertilizer",
+            ranges: [
+                (Interval::new(15, 52), -14),
+                (Interval::new(53, 54), -15),
+                (Interval::new(1, 14), 39),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let output: IntervalSet = [
+            Interval::new(1, 1),
+            Interval::new(40, 53),
+            Interval::new(2, 34),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(map.get_dest_from_range(&seed_range), output);
+    }
+}
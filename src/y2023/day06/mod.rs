@@ -0,0 +1,68 @@
+use crate::{error::finish, Answer, Error, Result, Solver};
+pub use parser::Race;
+
+pub mod parser;
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input<'a> = (Vec<Race>, Race);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part_1(&input.0)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part_2(&input.1)?.into())
+    }
+}
+
+/// Part 2 reads the same sheet with the spaces between digits ignored, so both readings are kept.
+pub fn parse(input: &str) -> Result<(Vec<Race>, Race)> {
+    let races = finish(input, parser::parse_game(input))?;
+    let race = finish(input, parser::parse_game2(input))?;
+    Ok((races, race))
+}
+
+pub fn winning_ways(race: &Race) -> Result<u64> {
+    match race.winning_hold_duration() {
+        Some((least, max)) => Ok(max - least + 1),
+        None => Err(Error::new(format!("{:?} cannot be won", race))),
+    }
+}
+
+pub fn solve_part_1(races: &[Race]) -> Result<u64> {
+    let mut total = 1;
+    for r in races {
+        total *= winning_ways(r)?;
+    }
+    Ok(total)
+}
+
+pub fn solve_part_2(race: &Race) -> Result<u64> {
+    winning_ways(race)
+}
+
+#[cfg(test)]
+mod tests {
+    crate::sample_tests!(super::Day6 {
+        could_solve_part_1: One { file "sample.txt" => 288 }
+        could_solve_part_2: Two { file "sample.txt" => 71503 }
+    });
+
+    #[test]
+    fn could_reject_trailing_garbage() {
+        let input = format!("{}Speed: 1 2 3\n", include_str!("sample.txt"));
+        let err = super::parse(&input).unwrap_err();
+        assert_eq!(err.message, "expected end of input");
+        assert_eq!(err.source_line.as_deref(), Some("Speed: 1 2 3"));
+        assert!(super::parse("Time: 7 15\nDistance: 9 40 x").is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    error::IResult,
+    parse::{header, numbers},
+};
+use nom::{
+    bytes::complete::take_till1,
+    character::complete,
+    error::{ErrorKind, FromExternalError},
+    multi::separated_list1,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, ParserExt};
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+impl Race {
+    pub fn new(time: u64, distance: u64) -> Self {
+        Self { time, distance }
+    }
+
+    fn wins(&self, hold: u64) -> bool {
+        hold as u128 * (self.time - hold) as u128 > self.distance as u128
+    }
+
+    /// Holds `h` win when `h * (time - h) > distance`, i.e. strictly between the roots of
+    /// `h² - time·h + distance`. The integer square root can be one off, so the lower bound is
+    /// nudged onto the first winning hold, and the upper one follows by symmetry.
+    pub fn winning_hold_duration(&self) -> Option<(u64, u64)> {
+        let best = self.time / 2;
+        if !self.wins(best) {
+            return None;
+        }
+        let time = self.time as u128;
+        let root = (time * time - 4 * self.distance as u128).isqrt();
+        let mut least = ((time - root) / 2) as u64;
+        while !self.wins(least) {
+            least += 1;
+        }
+        while least > 0 && self.wins(least - 1) {
+            least -= 1;
+        }
+        Some((least, self.time - least))
+    }
+
+    /// Tries every hold from both ends; kept to cross-check [`Race::winning_hold_duration`].
+    #[cfg(test)]
+    fn winning_hold_duration_scan(&self) -> Option<(u64, u64)> {
+        let mut first_value = None;
+        let mut last_value = None;
+        for i in 1..self.time {
+            let distance = i * (self.time - i);
+            if distance > self.distance {
+                first_value = Some(i);
+                break;
+            }
+        }
+        for i in (1..self.time).rev() {
+            let distance = i * (self.time - i);
+            if distance > self.distance {
+                last_value = Some(i);
+                break;
+            }
+        }
+        Some((first_value?, last_value?))
+    }
+}
+
+pub fn parse_game(input: &str) -> IResult<'_, Vec<Race>> {
+    let (input, time_list) =
+        header("Time", numbers(complete::u64).context("times")).parse(input)?;
+    let (input, _) = complete::line_ending(input)?;
+    let (rest, distance_list) =
+        header("Distance", numbers(complete::u64).context("distances")).parse(input)?;
+    if distance_list.len() != time_list.len() {
+        let message = format!("{} distances, one per time", time_list.len());
+        return Err(nom::Err::Failure(ErrorTree::from_external_error(
+            input,
+            ErrorKind::Count,
+            message,
+        )));
+    }
+    let mut rs = vec![];
+    for i in 0..time_list.len() {
+        rs.push(Race {
+            time: time_list[i],
+            distance: distance_list[i],
+        })
+    }
+    Ok((rest, rs))
+}
+
+fn parse_number(input: &str) -> IResult<'_, u64> {
+    let (input, _) = take_till1(|c: char| c.is_ascii_digit())(input)?;
+    let (rest, number) = take_till1(|c: char| c == '\n' || c == '\r')(input)?;
+    let number = number.replace(' ', "").parse::<u64>().map_err(|e| {
+        nom::Err::Failure(ErrorTree::from_external_error(input, ErrorKind::MapRes, e))
+    })?;
+    Ok((rest, number))
+}
+
+pub fn parse_game2(input: &str) -> IResult<'_, Race> {
+    let (rest, value) =
+        separated_list1(complete::line_ending, parse_number.context("kerned number"))
+            .parse(input)?;
+    if value.len() < 2 {
+        return Err(nom::Err::Failure(ErrorTree::from_external_error(
+            input,
+            ErrorKind::Count,
+            "a time and a distance",
+        )));
+    }
+    Ok((
+        rest,
+        Race {
+            time: value[0],
+            distance: value[1],
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn could_parse_race() {
+        let input = include_str!("./sample.txt");
+        let output = vec![
+            Race {
+                time: 7,
+                distance: 9,
+            },
+            Race {
+                time: 15,
+                distance: 40,
+            },
+            Race {
+                time: 30,
+                distance: 200,
+            },
+        ];
+        assert_eq!(parse_game(input).unwrap().1, output);
+    }
+
+    #[test]
+    fn could_parse_race2() {
+        let input = include_str!("./sample.txt");
+        let output = Race {
+            time: 71530,
+            distance: 940200,
+        };
+        assert_eq!(parse_game2(input).unwrap().1, output);
+    }
+
+    #[test]
+    fn could_parse_either_line_ending() {
+        let input = include_str!("./sample.txt");
+        let crlf = format!("{}\r\n", input.replace('\n', "\r\n"));
+        assert_eq!(parse_game(&crlf).unwrap().1, parse_game(input).unwrap().1);
+        assert_eq!(parse_game2(&crlf).unwrap().1, parse_game2(input).unwrap().1);
+    }
+
+    #[test]
+    fn could_calculate_winning_duration() {
+        let race = Race {
+            time: 7,
+            distance: 9,
+        };
+        let output = Some((2, 5));
+        assert_eq!(race.winning_hold_duration(), output);
+        assert_eq!(race.winning_hold_duration_scan(), output);
+    }
+
+    /// Short races, with distances clustered around the best one can do so ties and
+    /// unwinnable races come up often.
+    fn race() -> impl Strategy<Value = Race> {
+        (0..10_000u64)
+            .prop_flat_map(|time| {
+                let best = (time / 2) * (time - time / 2);
+                (Just(time), 0..=best + 1, best.saturating_sub(3)..=best + 1)
+            })
+            .prop_flat_map(|(time, anywhere, near_best)| {
+                prop_oneof![Just(anywhere), Just(near_best)]
+                    .prop_map(move |distance| Race { time, distance })
+            })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(5000))]
+
+        #[test]
+        fn could_match_scan(race in race()) {
+            prop_assert_eq!(race.winning_hold_duration(), race.winning_hold_duration_scan());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::io::BufRead;
+
+use crate::{
+    error::finish,
+    stream::{for_each_line, parse_line},
+    Answer, Result, Solver,
+};
+pub use parser::{Hand, Hand2};
+
+pub mod parser;
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<(usize, Hand<'a>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+}
+
+/// Every hand with the 1-based input line it is on, blank lines skipped.
+pub fn parse(input: &str) -> Result<Vec<(usize, Hand<'_>)>> {
+    let mut hands = vec![];
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let hand = finish(input, parser::parse_hand(line))?;
+        hands.push((i + 1, hand));
+    }
+    Ok(hands)
+}
+
+pub fn solve_part1(hands: &[(usize, Hand<'_>)]) -> Result<i64> {
+    let mut total_winnings = 0;
+    let mut hands = hands.iter().collect::<Vec<_>>();
+    hands.sort_by_key(|(_, hand)| hand);
+    for (rank, (line, hand)) in (1..).zip(hands) {
+        crate::trace!(
+            line: *line,
+            "rank",
+            hand = hand,
+            kind = hand.kind(),
+            rank = rank,
+            bet = hand.bet,
+        );
+        total_winnings += hand.bet * rank;
+    }
+    Ok(total_winnings)
+}
+
+pub fn solve_part2(hands: &[(usize, Hand<'_>)]) -> Result<i64> {
+    let mut total_winnings = 0;
+    let mut hands: Vec<_> = hands
+        .iter()
+        .map(|(line, hand)| (*line, Hand2(hand.clone())))
+        .collect();
+    hands.sort_by(|(_, a), (_, b)| a.cmp(b));
+    for (rank, (line, hand)) in (1..).zip(hands) {
+        crate::trace!(
+            line: line,
+            "rank",
+            hand = hand.0,
+            kind = hand.kind(),
+            rank = rank,
+            bet = hand.0.bet,
+        );
+        total_winnings += hand.0.bet * rank;
+    }
+    Ok(total_winnings)
+}
+
+/// Both parts from `reader` without keeping its text. Every hand has to be ranked against all
+/// the others, so each is kept as its cards and bet, 16 bytes, and the list is sorted in place
+/// once per part. Each sort works out every hand's key once and holds it with the hand's index,
+/// another 12 bytes per hand, until it is done.
+pub fn solve_stream(reader: impl BufRead) -> Result<(i64, i64)> {
+    let mut hands: Vec<([u8; 5], i64)> = vec![];
+    for_each_line(reader, |number, line| {
+        let hand = parse_line(number, line, parser::parse_hand(line))?;
+        hands.push((hand.cards(), hand.bet));
+        Ok(())
+    })?;
+    // Every card was checked while parsing, so each `Hand::new` is `Some`.
+    hands.sort_by_cached_key(|(cards, _)| Hand::new(cards, 0).map(|hand| hand.key()));
+    let part1 = winnings(&hands);
+    // Only identical cards have equal keys under either rule, so they stay in input order.
+    hands.sort_by_cached_key(|(cards, _)| Hand::new(cards, 0).map(|hand| Hand2(hand).key()));
+    Ok((part1, winnings(&hands)))
+}
+
+fn winnings(ranked: &[([u8; 5], i64)]) -> i64 {
+    (1..).zip(ranked).map(|(rank, (_, bet))| bet * rank).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sample_tests!(Day7 {
+        could_solve_part1: One { file "sample.txt" => 6440 }
+        could_solve_part2: Two { file "sample.txt" => 5905 }
+    });
+
+    #[test]
+    fn could_solve_stream() {
+        let input = include_str!("sample.txt");
+        let parsed = Day7::parse(input).unwrap();
+        let expected = (solve_part1(&parsed).unwrap(), solve_part2(&parsed).unwrap());
+        assert_eq!(solve_stream(input.as_bytes()).unwrap(), expected);
+        let input = "KK677 3\n32T3K 1\nKK677 7\nJJ677 5\n32T3K 2";
+        let parsed = Day7::parse(input).unwrap();
+        let expected = (solve_part1(&parsed).unwrap(), solve_part2(&parsed).unwrap());
+        assert_eq!(solve_stream(input.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn could_explain_ranks() {
+        let hands = Day7::parse(include_str!("sample.txt")).unwrap();
+        let (_, events) = crate::trace::collect(|| solve_part2(&hands));
+        let events: Vec<_> = events.iter().map(|event| event.to_string()).collect();
+        assert_eq!(
+            events,
+            vec![
+                "line 1: rank hand=32T3K kind=\"one pair\" rank=1 bet=765",
+                "line 3: rank hand=KK677 kind=\"two pair\" rank=2 bet=28",
+                "line 2: rank hand=T55J5 kind=\"four of a kind\" rank=3 bet=684",
+                "line 5: rank hand=QQQJA kind=\"four of a kind\" rank=4 bet=483",
+                "line 4: rank hand=KTJJT kind=\"four of a kind\" rank=5 bet=220",
+            ]
+        );
+    }
+
+    #[test]
+    fn could_explain_ranks_after_blank_lines() {
+        let hands = Day7::parse("\nKK677 28\n\n32T3K 765\n").unwrap();
+        let (_, events) = crate::trace::collect(|| solve_part1(&hands));
+        let lines: Vec<_> = events.iter().map(|event| event.line).collect();
+        assert_eq!(lines, vec![Some(4), Some(2)]);
+    }
+}
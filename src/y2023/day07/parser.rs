@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use crate::error::IResult;
+use nom::{
+    bytes::complete::take_while_m_n,
+    character::complete::{self, space1},
+    sequence::separated_pair,
+    Parser,
+};
+use nom_supreme::ParserExt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand<'a> {
+    cards: &'a str,
+    pub bet: i64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand2<'a>(pub Hand<'a>);
+
+fn map_to_type_score(map: &HashMap<char, u32>) -> u32 {
+    if map.iter().any(|(_, v)| *v == 5) {
+        7
+    } else if map.iter().any(|(_, v)| *v == 4) {
+        6
+    } else if map.iter().any(|(_, v)| *v == 3) && map.iter().any(|(_, v)| *v == 2) {
+        5
+    } else if map.iter().any(|(_, v)| *v == 3) {
+        4
+    } else if map.iter().filter(|(_, v)| **v == 2).count() == 2 {
+        3
+    } else if map.iter().any(|(_, v)| *v == 2) {
+        2
+    } else {
+        1
+    }
+}
+
+/// The name of a [`map_to_type_score`].
+fn type_name(score: u32) -> &'static str {
+    match score {
+        7 => "five of a kind",
+        6 => "four of a kind",
+        5 => "full house",
+        4 => "three of a kind",
+        3 => "two pair",
+        2 => "one pair",
+        _ => "high card",
+    }
+}
+
+impl Hand2<'_> {
+    /// The type with jokers counted as whatever helps most.
+    pub fn kind(&self) -> &'static str {
+        type_name(self.to_type_number())
+    }
+
+    /// [`Hand::key`] with jokers counted as whatever helps most, but weakest on their own.
+    pub fn key(&self) -> (u8, [u8; 5]) {
+        key(self.to_type_number(), self.0.cards, char_to_power2)
+    }
+
+    fn to_type_number(&self) -> u32 {
+        let mut map = HashMap::new();
+        for c in self.0.cards.chars() {
+            let count = map.entry(c).or_default();
+            *count += 1;
+        }
+        if let Some(v) = map.remove(&'J') {
+            match map.iter().max_by_key(|(_, count)| **count) {
+                Some((&top_key, _)) => map.entry(top_key).and_modify(|j| *j += v),
+                None => return 7,
+            };
+        };
+        map_to_type_score(&map)
+    }
+}
+
+impl PartialOrd for Hand2<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Hand2<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<'a> Hand<'a> {
+    /// `None` unless `cards` are five of `AKQJT98765432`.
+    pub fn new(cards: &'a [u8; 5], bet: i64) -> Option<Self> {
+        let cards = std::str::from_utf8(cards).ok()?;
+        cards.chars().all(is_card).then_some(Self { cards, bet })
+    }
+
+    /// The cards packed into five bytes.
+    pub fn cards(&self) -> [u8; 5] {
+        let mut cards = [0; 5];
+        cards.copy_from_slice(self.cards.as_bytes());
+        cards
+    }
+
+    pub fn kind(&self) -> &'static str {
+        type_name(self.to_type_number())
+    }
+
+    /// What the hand is ranked by: its type, then the strength of each card in order.
+    pub fn key(&self) -> (u8, [u8; 5]) {
+        key(self.to_type_number(), self.cards, char_to_power)
+    }
+
+    fn to_type_number(&self) -> u32 {
+        let mut map = HashMap::new();
+        for c in self.cards.chars() {
+            let count = map.entry(c).or_default();
+            *count += 1;
+        }
+        map_to_type_score(&map)
+    }
+}
+
+impl std::fmt::Display for Hand<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.cards)
+    }
+}
+
+impl PartialOrd for Hand<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+fn key(type_number: u32, cards: &str, power: fn(char) -> u32) -> (u8, [u8; 5]) {
+    let mut powers = [0; 5];
+    for (power_of_card, c) in powers.iter_mut().zip(cards.chars()) {
+        *power_of_card = power(c) as u8;
+    }
+    (type_number as u8, powers)
+}
+
+fn is_card(c: char) -> bool {
+    "AKQJT98765432".contains(c)
+}
+
+fn char_to_power(c: char) -> u32 {
+    match c {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' => 11,
+        'T' => 10,
+        _ => c.to_digit(10).unwrap_or(0),
+    }
+}
+
+fn char_to_power2(c: char) -> u32 {
+    match c {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' => 1,
+        'T' => 10,
+        _ => c.to_digit(10).unwrap_or(0),
+    }
+}
+
+pub fn parse_hand(input: &str) -> IResult<'_, Hand<'_>> {
+    let (input, (cards, hand)) = separated_pair(
+        take_while_m_n(5, 5, is_card).context("cards"),
+        space1,
+        complete::i64.context("bet"),
+    )
+    .context("hand")
+    .parse(input)?;
+    Ok((input, Hand { cards, bet: hand }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn could_parse_hand() {
+        let input = "32T3K 765";
+        let expect = Hand {
+            cards: "32T3K",
+            bet: 765,
+        };
+
+        assert_eq!(parse_hand(input).unwrap(), ("", expect));
+    }
+
+    #[test]
+    fn could_compare_hand() {
+        let hand1 = parse_hand("QAAQT 665").unwrap().1;
+        let hand2 = parse_hand("5K355 312").unwrap().1;
+        assert!(hand1 < hand2);
+    }
+
+    #[test]
+    fn could_check_new_hand() {
+        assert_eq!(
+            Hand::new(b"32T3K", 765),
+            Some(parse_hand("32T3K 765").unwrap().1)
+        );
+        assert_eq!(Hand::new(b"32T3X", 765), None);
+        assert_eq!(Hand::new(&[b'3', b'2', 0xff, b'3', b'K'], 765), None);
+        assert_eq!(
+            Hand2(Hand::new(b"JJJJJ", 1).unwrap()).kind(),
+            "five of a kind"
+        );
+        assert_eq!(
+            Hand2(Hand::new(b"JJ2JJ", 1).unwrap()).kind(),
+            "five of a kind"
+        );
+        assert_eq!(
+            Hand::new(b"JKKK2", 1).unwrap().key(),
+            (4, [11, 13, 13, 13, 2])
+        );
+        assert_eq!(
+            Hand2(Hand::new(b"JKKK2", 1).unwrap()).key(),
+            (6, [1, 13, 13, 13, 2])
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    cycle::{self, Cycle},
+    error::finish,
+    Answer, Error, Result, Solver,
+};
+use num::integer::lcm;
+
+pub use parser::{Instruction, Node};
+
+pub mod parser;
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input<'a> = (Instruction, Vec<Node<'a>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Answer> {
+        let (instruction, nodes) = input;
+        Ok(part1(instruction, nodes)?.into())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Answer> {
+        let (instruction, nodes) = input;
+        Ok(part2(instruction, nodes)?.into())
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Instruction, Vec<Node<'_>>)> {
+    finish(input, parser::parse(input))
+}
+
+/// Left and right neighbour of every node, checked to point at nodes that exist.
+#[derive(Debug)]
+struct Network<'a> {
+    map: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+impl<'a> Network<'a> {
+    fn new(nodes: &[Node<'a>]) -> Result<Self> {
+        let map: HashMap<_, _> = nodes
+            .iter()
+            .map(|node| (node.name, (node.left, node.right)))
+            .collect();
+        for node in nodes {
+            for next in [node.left, node.right] {
+                if !map.contains_key(next) {
+                    return Err(Error::new(format!("node {} is not in the network", next)));
+                }
+            }
+        }
+        Ok(Self { map })
+    }
+
+    fn move_node(&self, current_node: &str, direction: char) -> &'a str {
+        let (left, right) = self.map[current_node];
+        match direction {
+            'L' => left,
+            'R' => right,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn part1(instruction: &Instruction, nodes: &[Node<'_>]) -> Result<i64> {
+    let mut instruction = instruction.clone();
+    let network = Network::new(nodes)?;
+    let mut current_node = "AAA";
+    if !network.map.contains_key(current_node) {
+        return Err(Error::new("node AAA is not in the network"));
+    }
+    let mut total_move = 0;
+    // Coming back to a node at the same point of the instructions means walking in circles.
+    let mut seen = HashSet::new();
+    while current_node != "ZZZ" {
+        if !seen.insert((current_node, instruction.index)) {
+            return Err(Error::new("node ZZZ is not reachable from AAA"));
+        }
+        current_node = network.move_node(current_node, instruction.next_direction());
+        total_move += 1;
+    }
+    Ok(total_move)
+}
+
+/// Walks from `start` until a (node, instruction index) pair repeats.
+fn get_node_loop(start: &str, instruction: &Instruction, network: &Network<'_>) -> Cycle {
+    cycle::hashed(
+        (start, instruction.index),
+        |&(node, index)| {
+            let (direction, index) = instruction.peek(index);
+            (network.move_node(node, direction), index)
+        },
+        |(node, _)| node.ends_with('Z'),
+    )
+}
+
+// PART 2 ONLY WORKS BECAUSE ALL THE NODES TRAVERSAL GOES THROUGH THE WHOLE LOOP AFTER THE
+// INSTRUCTION AND IT EXIST ONLY ONE END NODE
+// EXTRA INSTRUCTION IS NEEDED IF GET_NODE_LOOP RETURN MORE THAN ONE CYCLE HIT OR THE HIT IS NOT
+// AT A MULTIPLE OF THE LOOP SIZE
+pub fn part2(instruction: &Instruction, nodes: &[Node<'_>]) -> Result<i64> {
+    let network = Network::new(nodes)?;
+    let mut loop_sizes = vec![];
+    for node in network.map.keys().filter(|node| node.ends_with('A')) {
+        let cycle = get_node_loop(node, instruction, &network);
+        if cycle.cycle_hits.is_empty() {
+            return Err(Error::new(format!(
+                "no end node is reachable from {}",
+                node
+            )));
+        }
+        loop_sizes.push(cycle.length as i64);
+    }
+    Ok(loop_sizes.into_iter().fold(1, lcm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::sample_tests!(Day8 {
+        could_solve_part1: One {
+            r"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)" => 2,
+            r"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)" => 6,
+        }
+        could_solve_part2: Two {
+            r"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)" => 6,
+        }
+    });
+
+    #[test]
+    fn could_find_node_loop() {
+        let input = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\nXXX = (XXX, XXX)";
+        let (instruction, nodes) = Day8::parse(input).unwrap();
+        let network = Network::new(&nodes).unwrap();
+        let cycle = get_node_loop("11A", &instruction, &network);
+        assert_eq!((cycle.prefix, cycle.length), (1, 2));
+        assert_eq!(cycle.cycle_hits, vec![2]);
+        let err = Network::new(&Day8::parse("L\n\nAAA = (BBB, AAA)").unwrap().1).unwrap_err();
+        assert_eq!(err.message, "node BBB is not in the network");
+    }
+
+    #[test]
+    fn could_report_unreachable_end() {
+        let input = "LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, BBB)\nZZZ = (ZZZ, ZZZ)";
+        let err = Day8::part_one(&Day8::parse(input).unwrap()).unwrap_err();
+        assert_eq!(err.message, "node ZZZ is not reachable from AAA");
+    }
+
+    #[test]
+    fn could_reject_trailing_garbage() {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let err = Day8::parse(&format!("{}\nnot a node\n", input)).unwrap_err();
+        assert_eq!(
+            err.position,
+            Some(crate::error::Position { line: 6, column: 1 })
+        );
+        let err = Day8::parse(&format!("{} extra", input)).unwrap_err();
+        assert_eq!(err.source_line.as_deref(), Some("ZZZ = (ZZZ, ZZZ) extra"));
+    }
+}
@@ -1,8 +1,11 @@
+use crate::{error::IResult, parse::blank_line};
 use nom::{
-    bytes::complete::{tag, take, take_till1},
+    bytes::complete::{take, take_while1},
+    character::complete::line_ending,
     multi::separated_list1,
-    IResult,
+    Parser,
 };
+use nom_supreme::{tag::complete::tag, ParserExt};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Instruction {
@@ -10,23 +13,30 @@ pub struct Instruction {
     pub index: usize,
 }
 impl Instruction {
-    pub fn next(&mut self) -> char {
+    pub fn next_direction(&mut self) -> char {
         let direction = self.directions[self.index];
         self.index = (self.index + 1) % self.directions.len();
         direction
     }
+
+    /// The direction at `index` and the index after it, without moving.
+    pub fn peek(&self, index: usize) -> (char, usize) {
+        (self.directions[index], (index + 1) % self.directions.len())
+    }
 }
 
 #[derive(Debug, PartialEq)]
-pub struct Node {
-    pub name: &'static str,
-    pub left: &'static str,
-    pub right: &'static str,
+pub struct Node<'a> {
+    pub name: &'a str,
+    pub left: &'a str,
+    pub right: &'a str,
 }
 
-fn parse_instructions(input: &'static str) -> IResult<&str, Instruction> {
-    let (input, instructions) = take_till1(|c| c == '\n')(input)?;
-    let (input, _) = tag("\n\n")(input)?;
+fn parse_instructions(input: &str) -> IResult<'_, Instruction> {
+    let (input, instructions) = take_while1(|c| c == 'L' || c == 'R')
+        .context("instructions")
+        .parse(input)?;
+    let (input, _) = blank_line(input)?;
     let directions = instructions.chars().collect();
     Ok((
         input,
@@ -37,19 +47,19 @@ fn parse_instructions(input: &'static str) -> IResult<&str, Instruction> {
     ))
 }
 
-fn parse_node(input: &'static str) -> IResult<&str, Node> {
-    let (input, name) = take(3usize)(input)?;
+fn parse_node(input: &str) -> IResult<'_, Node<'_>> {
+    let (input, name) = take(3usize).context("node name").parse(input)?;
     let (input, _) = tag(" = (")(input)?;
-    let (input, left) = take(3usize)(input)?;
+    let (input, left) = take(3usize).context("left node").parse(input)?;
     let (input, _) = tag(", ")(input)?;
-    let (input, right) = take(3usize)(input)?;
+    let (input, right) = take(3usize).context("right node").parse(input)?;
     let (input, _) = tag(")")(input)?;
     Ok((input, Node { name, left, right }))
 }
 
-pub fn parse(input: &'static str) -> IResult<&str, (Instruction, Vec<Node>)> {
+pub fn parse(input: &str) -> IResult<'_, (Instruction, Vec<Node<'_>>)> {
     let (input, instructions) = parse_instructions(input)?;
-    let (input, nodes) = separated_list1(tag("\n"), parse_node)(input)?;
+    let (input, nodes) = separated_list1(line_ending, parse_node.context("node")).parse(input)?;
     Ok((input, (instructions, nodes)))
 }
 
@@ -91,6 +101,14 @@ ZZZ = (ZZZ, ZZZ)";
         assert_eq!(parse(input).unwrap().1, expected);
     }
 
+    #[test]
+    fn could_parse_either_line_ending() {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        let crlf = input.replace('\n', "\r\n");
+        let (instruction, nodes) = parse(&crlf).unwrap().1;
+        assert_eq!((instruction, nodes.len()), (parse(input).unwrap().1 .0, 3));
+    }
+
     #[test]
     fn could_parse_node() {
         let input = "DBQ = (RTP, NBX)";
@@ -0,0 +1,25 @@
+use aoc::{
+    render::{render_frames, Mode},
+    voxel::Point3,
+    y2022::day18,
+};
+
+#[test]
+fn could_measure_day18_droplet() {
+    let droplets = day18::parse(include_str!("../src/y2022/day18/sample.txt")).unwrap();
+    assert!(droplets.contains(&Point3::new(2, 2, 2)));
+    assert_eq!(day18::solve(&droplets).unwrap(), 64);
+    assert_eq!(day18::solve2(&droplets).unwrap(), 58);
+    assert_eq!(day18::model::exterior_surface(&droplets), 58);
+}
+
+#[test]
+fn could_draw_day18_slices() {
+    let droplets = day18::parse("1,1,1\n2,1,1").unwrap();
+    let frames = day18::slices(&droplets);
+    assert_eq!(frames.len(), 1);
+    assert_eq!(
+        render_frames(&frames, Mode::Plain),
+        "z = 1\n....\n.##.\n....\n"
+    );
+}
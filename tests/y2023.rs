@@ -0,0 +1,108 @@
+use aoc::{
+    y2023::{day01, day02, day03, day04, day05, day06, day07, day08},
+    Answer, Part, Puzzle,
+};
+
+#[test]
+fn could_solve_day01() {
+    let input = include_str!("../src/y2023/day01/sample.txt");
+    assert_eq!(day01::solve(input).unwrap(), 142);
+    assert_eq!(day01::solve_stream(input.as_bytes()).unwrap(), (142, 142));
+    let input = include_str!("../src/y2023/day01/sample2.txt");
+    assert_eq!(day01::solve2(input).unwrap(), 281);
+}
+
+#[test]
+fn could_filter_day02_games() {
+    let games = day02::parse(include_str!("../src/y2023/day02/sample.txt")).unwrap();
+    let possible: Vec<_> = games
+        .iter()
+        .filter(|game| day02::is_possible(game))
+        .map(|game| game.id)
+        .collect();
+    assert_eq!(possible, vec![1, 2, 5]);
+    assert_eq!(day02::power(&games[0]), 48);
+    assert_eq!(day02::solve_part_2(&games).unwrap(), 2286);
+}
+
+#[test]
+fn could_find_day03_part_numbers() {
+    let schematic = day03::parse(include_str!("../src/y2023/day03/sample.txt")).unwrap();
+    let not_parts: Vec<_> = schematic
+        .numbers
+        .iter()
+        .filter(|number| !number.is_part(&schematic.grid))
+        .map(|number| number.value)
+        .collect();
+    assert_eq!(not_parts, vec![114, 58]);
+    assert_eq!(day03::part1(&schematic).unwrap(), 4361);
+    assert_eq!(day03::part2(&schematic).unwrap(), 467835);
+}
+
+#[test]
+fn could_score_day04_cards() {
+    let cards = day04::parse(include_str!("../src/y2023/day04/sample.txt")).unwrap();
+    let scores: Vec<_> = cards.iter().map(|card| card.get_score()).collect();
+    assert_eq!(scores, vec![8, 2, 2, 1, 0, 0]);
+    assert_eq!(cards[0].get_next_cards(), vec![2, 3, 4, 5]);
+    assert_eq!(day04::solve2(&cards).unwrap(), 30);
+}
+
+#[test]
+fn could_map_day05_seeds() {
+    let (seeds, maps) = day05::parse(include_str!("../src/y2023/day05/sample.txt")).unwrap();
+    assert_eq!(seeds, vec![79, 14, 55, 13]);
+    let names: Vec<_> = maps.iter().map(|map| map.to()).collect();
+    assert_eq!(names.last(), Some(&"location"));
+    let location = maps.iter().fold(79, |value, map| map.get_dest(value));
+    assert_eq!(location, 82);
+    assert_eq!(day05::solve_part_1(&seeds, &maps).unwrap(), 35);
+    assert_eq!(day05::solve_part_2(&seeds, &maps).unwrap(), 46);
+}
+
+#[test]
+fn could_count_day06_winning_holds() {
+    assert_eq!(day06::Race::new(7, 9).winning_hold_duration(), Some((2, 5)));
+    assert_eq!(day06::Race::new(3, 9).winning_hold_duration(), None);
+    let (races, race) = day06::parse(include_str!("../src/y2023/day06/sample.txt")).unwrap();
+    assert_eq!(day06::solve_part_1(&races).unwrap(), 288);
+    assert_eq!(day06::winning_ways(&race).unwrap(), 71503);
+}
+
+#[test]
+fn could_rank_day07_hands() {
+    let hands = day07::parse(include_str!("../src/y2023/day07/sample.txt")).unwrap();
+    assert_eq!(
+        hands[3],
+        (4, day07::parser::parse_hand("KTJJT 220").unwrap().1)
+    );
+    assert_eq!(hands[3].1.kind(), "two pair");
+    assert_eq!(day07::Hand2(hands[3].1.clone()).kind(), "four of a kind");
+    assert_eq!(day07::solve_part1(&hands).unwrap(), 6440);
+    assert_eq!(day07::solve_part2(&hands).unwrap(), 5905);
+}
+
+#[test]
+fn could_walk_day08_network() {
+    let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+    let (instruction, nodes) = day08::parse(input).unwrap();
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(day08::part1(&instruction, &nodes).unwrap(), 6);
+    assert!(day08::parse("LR\n\nAAA = (BBB").is_err());
+}
+
+#[test]
+fn could_register_days_from_outside_the_crate() {
+    let puzzle = Puzzle::new::<day04::Day4>();
+    assert_eq!((puzzle.year, puzzle.day), (2023, 4));
+    let answers = puzzle
+        .solve(include_str!("../src/y2023/day04/sample.txt"), &Part::ALL)
+        .unwrap();
+    assert_eq!(
+        answers,
+        vec![
+            (Part::One, Answer::from(13u32)),
+            (Part::Two, Answer::from(30u32))
+        ]
+    );
+}